@@ -0,0 +1,30 @@
+//! Polygons and cut lines shared by the benches.
+
+// Each bench only uses some of these.
+#![allow(dead_code)]
+
+use polygon_slicing::{Line, Point};
+
+/// Regular polygon approximating a circle with the given number of vertices.
+pub fn circle(vertex_count: usize) -> Vec<Point> {
+    (0..vertex_count)
+        .map(|i| {
+            let angle = i as f64 / vertex_count as f64 * std::f64::consts::TAU;
+            Point::new(angle.cos(), angle.sin())
+        })
+        .collect()
+}
+
+/// Cut lines crossing the whole circle at varying angles and offsets.
+pub fn cuts(line_count: usize) -> Vec<Line> {
+    (0..line_count)
+        .map(|i| {
+            let angle = i as f64 * 2.399_963;
+            let offset = (i as f64 / line_count as f64 - 0.5) * 1.5;
+            let (sin, cos) = angle.sin_cos();
+            let center = Point::new(-sin * offset, cos * offset);
+            let direction = Point::new(cos, sin) * 2.0;
+            Line::new(center - direction, center + direction)
+        })
+        .collect()
+}
@@ -0,0 +1,60 @@
+//! Compares `find_intersections` against testing every edge of a large polygon.
+//! Run with `cargo bench --bench find_intersections`.
+
+mod common;
+
+use std::hint::black_box;
+
+use common::circle;
+use criterion::{criterion_group, criterion_main, Criterion};
+use polygon_slicing::{find_intersections, line_segment_intersection, points_equal, Line, Point};
+
+/// Testing every edge with the full intersection math, without any reject.
+/// Duplicates are removed the same way as in `find_intersections`.
+fn every_edge(polygon_points: &[Point], line: &Line) -> Vec<Point> {
+    let mut intersection_points = Vec::new();
+    for i in 0..polygon_points.len() {
+        let edge = Line::new(
+            polygon_points[i],
+            polygon_points[(i + 1) % polygon_points.len()],
+        );
+        if let Some(point) = line_segment_intersection(line, &edge) {
+            intersection_points.push(point);
+        }
+    }
+    let direction = line.p2 - line.p1;
+    intersection_points.sort_by(|a, b| {
+        (*a - line.p1)
+            .dot(&direction)
+            .total_cmp(&(*b - line.p1).dot(&direction))
+    });
+    intersection_points.dedup_by(|a, b| points_equal(a, b));
+    intersection_points
+}
+
+fn bounding_box_reject(c: &mut Criterion) {
+    let polygon_points = circle(10_000);
+    let cases = [
+        ("short cut", Line::from_coords(0.99, -0.01, 1.01, 0.01)),
+        ("long cut", Line::from_coords(-2.0, -0.3, 2.0, 0.3)),
+    ];
+
+    let mut group = c.benchmark_group("find_intersections");
+    for (name, line) in cases {
+        assert_eq!(
+            find_intersections(&polygon_points, &line),
+            every_edge(&polygon_points, &line)
+        );
+
+        group.bench_function(format!("{}/with bounding box reject", name), |b| {
+            b.iter(|| find_intersections(black_box(&polygon_points), black_box(&line)))
+        });
+        group.bench_function(format!("{}/testing every edge", name), |b| {
+            b.iter(|| every_edge(black_box(&polygon_points), black_box(&line)))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bounding_box_reject);
+criterion_main!(benches);
@@ -0,0 +1,49 @@
+//! Compares slicing with the `parallel` feature against a serial loop.
+//! Run with `cargo bench --bench parallel --features parallel`.
+
+mod common;
+
+use std::hint::black_box;
+
+use common::{circle, cuts};
+use criterion::{criterion_group, criterion_main, Criterion};
+use polygon_slicing::{get_all_fragments, split_polygon, Line, Point, SliceError};
+
+/// The same loop as `get_all_fragments` without the parallel feature.
+fn serial(polygon_points: &[Point], lines: &[Line]) -> Vec<Vec<Point>> {
+    let mut polygons = vec![polygon_points.to_vec()];
+    for line in lines {
+        let mut new_polygons = Vec::new();
+        for poly in &polygons {
+            match split_polygon(poly, line) {
+                Ok(mut split_result) => new_polygons.append(&mut split_result),
+                Err(SliceError::NoIntersection) => new_polygons.push(poly.clone()),
+                Err(error) => panic!("{}", error),
+            }
+        }
+        polygons = new_polygons;
+    }
+    polygons
+}
+
+fn parallel_against_serial(c: &mut Criterion) {
+    let polygon_points = circle(10_000);
+    let lines = cuts(100);
+    assert_eq!(
+        get_all_fragments(&polygon_points, &lines).unwrap(),
+        serial(&polygon_points, &lines)
+    );
+
+    let mut group = c.benchmark_group("get_all_fragments");
+    group.sample_size(10);
+    group.bench_function("parallel", |b| {
+        b.iter(|| get_all_fragments(black_box(&polygon_points), black_box(&lines)).unwrap())
+    });
+    group.bench_function("serial", |b| {
+        b.iter(|| serial(black_box(&polygon_points), black_box(&lines)))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, parallel_against_serial);
+criterion_main!(benches);
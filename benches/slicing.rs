@@ -0,0 +1,63 @@
+//! Baseline timings for the core geometry on growing polygons.
+//! Run with `cargo bench --bench slicing`, criterion keeps the previous
+//! run under `target/criterion` and reports the change against it.
+
+mod common;
+
+use std::hint::black_box;
+
+use common::{circle, cuts};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use polygon_slicing::{get_largest_polygon_area, line_segment_intersection, polygon_area, Line};
+
+const SIZES: [(&str, usize); 3] = [("small", 16), ("medium", 1_000), ("large", 100_000)];
+
+fn intersection(c: &mut Criterion) {
+    let line = Line::from_coords(-2.0, -0.3, 2.0, 0.3);
+    let crossing = Line::from_coords(0.0, -1.0, 0.1, 1.0);
+    let missing = Line::from_coords(3.0, -1.0, 3.1, 1.0);
+
+    let mut group = c.benchmark_group("line_segment_intersection");
+    group.bench_function("crossing", |b| {
+        b.iter(|| line_segment_intersection(black_box(&line), black_box(&crossing)))
+    });
+    group.bench_function("missing", |b| {
+        b.iter(|| line_segment_intersection(black_box(&line), black_box(&missing)))
+    });
+    group.finish();
+}
+
+fn area(c: &mut Criterion) {
+    let mut group = c.benchmark_group("polygon_area");
+    for (name, vertex_count) in SIZES {
+        let polygon_points = circle(vertex_count);
+        group.bench_with_input(
+            BenchmarkId::from_parameter(name),
+            &polygon_points,
+            |b, points| b.iter(|| polygon_area(black_box(points))),
+        );
+    }
+    group.finish();
+}
+
+fn largest_area(c: &mut Criterion) {
+    let mut group = c.benchmark_group("get_largest_polygon_area");
+    group.sample_size(10);
+    for (name, vertex_count) in SIZES {
+        let polygon_points = circle(vertex_count);
+        for line_count in [1, 10, 50] {
+            let lines = cuts(line_count);
+            group.bench_with_input(
+                BenchmarkId::new(name, format!("{} lines", line_count)),
+                &lines,
+                |b, lines| {
+                    b.iter(|| get_largest_polygon_area(black_box(&polygon_points), lines).unwrap())
+                },
+            );
+        }
+    }
+    group.finish();
+}
+
+criterion_group!(benches, intersection, area, largest_area);
+criterion_main!(benches);
@@ -0,0 +1,37 @@
+//! Slices an arbitrary polygon by arbitrary lines and checks that nothing
+//! panics and every fragment comes out with finite coordinates.
+//! Run from the repository root with `cargo +nightly fuzz run slice`.
+
+#![no_main]
+
+use arbitrary::Arbitrary;
+use libfuzzer_sys::fuzz_target;
+use polygon_slicing::{get_all_fragments, Line, Point};
+
+#[derive(Debug, Arbitrary)]
+struct Input {
+    polygon: Vec<(f64, f64)>,
+    lines: Vec<((f64, f64), (f64, f64))>,
+}
+
+fuzz_target!(|input: Input| {
+    // Large inputs only make each run slower, not more interesting.
+    let polygon: Vec<Point> = input.polygon.into_iter().take(64).map(Point::from).collect();
+    let lines: Vec<Line> = input
+        .lines
+        .into_iter()
+        .take(8)
+        .map(|(p1, p2)| Line::new(p1.into(), p2.into()))
+        .collect();
+
+    // NaN or infinite points, or lines that can't cut, e.g. zero length ones,
+    // give an error instead.
+    let Ok(fragments) = get_all_fragments(&polygon, &lines) else {
+        return;
+    };
+    for fragment in fragments {
+        for p in fragment {
+            assert!(p.x.is_finite() && p.y.is_finite(), "{} in a fragment", p);
+        }
+    }
+});
@@ -0,0 +1,234 @@
+use std::ops::Mul;
+
+use crate::{Point, Scalar};
+
+/// 2D affine transform stored as the top two rows of a 3x3 matrix:
+/// `x' = a * x + b * y + tx` and `y' = c * x + d * y + ty`.
+///
+/// Transforms compose like matrices, so `first_then_second = second * first`
+/// applies `first` before `second`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Affine2<T = f64> {
+    pub a: T,
+    pub b: T,
+    pub c: T,
+    pub d: T,
+    pub tx: T,
+    pub ty: T,
+}
+
+impl<T: Scalar> Affine2<T> {
+    pub fn identity() -> Self {
+        Affine2 {
+            a: T::ONE,
+            b: T::ZERO,
+            c: T::ZERO,
+            d: T::ONE,
+            tx: T::ZERO,
+            ty: T::ZERO,
+        }
+    }
+
+    pub fn translate(dx: T, dy: T) -> Self {
+        Affine2 {
+            tx: dx,
+            ty: dy,
+            ..Affine2::identity()
+        }
+    }
+
+    /// Counter-clockwise rotation about the origin.
+    pub fn rotate(radians: T) -> Self {
+        let (sin, cos) = radians.sin_cos();
+        Affine2 {
+            a: cos,
+            b: -sin,
+            c: sin,
+            d: cos,
+            ..Affine2::identity()
+        }
+    }
+
+    /// Scaling about the origin. A negative factor mirrors that axis.
+    pub fn scale(sx: T, sy: T) -> Self {
+        Affine2 {
+            a: sx,
+            d: sy,
+            ..Affine2::identity()
+        }
+    }
+
+    /// Counter-clockwise rotation about `pivot` instead of the origin.
+    pub fn rotate_about(pivot: &Point<T>, radians: T) -> Self {
+        Affine2::translate(pivot.x, pivot.y)
+            * Affine2::rotate(radians)
+            * Affine2::translate(-pivot.x, -pivot.y)
+    }
+
+    /// Uniform scaling about `pivot`, which stays where it is.
+    pub fn scale_about(pivot: &Point<T>, factor: T) -> Self {
+        Affine2::translate(pivot.x, pivot.y)
+            * Affine2::scale(factor, factor)
+            * Affine2::translate(-pivot.x, -pivot.y)
+    }
+
+    pub fn transform_point(&self, p: &Point<T>) -> Point<T> {
+        Point::new(
+            self.a * p.x + self.b * p.y + self.tx,
+            self.c * p.x + self.d * p.y + self.ty,
+        )
+    }
+
+    pub fn transform_polygon(&self, points: &[Point<T>]) -> Vec<Point<T>> {
+        points.iter().map(|p| self.transform_point(p)).collect()
+    }
+}
+
+impl<T: Scalar> Default for Affine2<T> {
+    fn default() -> Self {
+        Affine2::identity()
+    }
+}
+
+impl<T: Scalar> Mul for Affine2<T> {
+    type Output = Affine2<T>;
+
+    /// `self * other` applies `other` first, then `self`.
+    fn mul(self, other: Affine2<T>) -> Affine2<T> {
+        Affine2 {
+            a: self.a * other.a + self.b * other.c,
+            b: self.a * other.b + self.b * other.d,
+            c: self.c * other.a + self.d * other.c,
+            d: self.c * other.b + self.d * other.d,
+            tx: self.a * other.tx + self.b * other.ty + self.tx,
+            ty: self.c * other.tx + self.d * other.ty + self.ty,
+        }
+    }
+}
+
+/// Rotates the polygon counter-clockwise about `pivot`, see `Affine2::rotate_about`.
+pub fn rotate_about<T: Scalar>(points: &[Point<T>], pivot: &Point<T>, radians: T) -> Vec<Point<T>> {
+    Affine2::rotate_about(pivot, radians).transform_polygon(points)
+}
+
+/// Scales the polygon about `pivot`, see `Affine2::scale_about`.
+pub fn scale_about<T: Scalar>(points: &[Point<T>], pivot: &Point<T>, factor: T) -> Vec<Point<T>> {
+    Affine2::scale_about(pivot, factor).transform_polygon(points)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{rotate_about, scale_about, Affine2};
+    use crate::{points_equal, Point};
+
+    fn unit_square() -> Vec<Point> {
+        vec![
+            Point::new(0.0, 0.0),
+            Point::new(1.0, 0.0),
+            Point::new(1.0, 1.0),
+            Point::new(0.0, 1.0),
+        ]
+    }
+
+    fn assert_points_equal(actual: &[Point], expected: &[Point]) {
+        assert_eq!(actual.len(), expected.len());
+        for (a, b) in actual.iter().zip(expected) {
+            assert!(points_equal(a, b), "{} != {}", a, b);
+        }
+    }
+
+    #[test]
+    fn rotate_square_back_into_place() {
+        let quarter_turn = Affine2::rotate(std::f64::consts::FRAC_PI_2);
+        let rotated = quarter_turn.transform_polygon(&unit_square());
+        assert_points_equal(
+            &rotated,
+            &[
+                Point::new(0.0, 0.0),
+                Point::new(0.0, 1.0),
+                Point::new(-1.0, 1.0),
+                Point::new(-1.0, 0.0),
+            ],
+        );
+
+        // Shifting right by one puts the rotated square back over the original.
+        let moved_back = Affine2::translate(1.0, 0.0).transform_polygon(&rotated);
+        assert_points_equal(
+            &moved_back,
+            &[
+                Point::new(1.0, 0.0),
+                Point::new(1.0, 1.0),
+                Point::new(0.0, 1.0),
+                Point::new(0.0, 0.0),
+            ],
+        );
+    }
+
+    #[test]
+    fn rotate_about_center() {
+        let center = Point::new(0.5, 0.5);
+        let rotated = rotate_about(&unit_square(), &center, std::f64::consts::FRAC_PI_2);
+        // Same square, each corner moved on to the next one.
+        assert_points_equal(
+            &rotated,
+            &[
+                Point::new(1.0, 0.0),
+                Point::new(1.0, 1.0),
+                Point::new(0.0, 1.0),
+                Point::new(0.0, 0.0),
+            ],
+        );
+
+        let half_turn = rotate_about(
+            &[Point::new(3.0, 1.0)],
+            &Point::new(2.0, 1.0),
+            std::f64::consts::PI,
+        );
+        assert_points_equal(&half_turn, &[Point::new(1.0, 1.0)]);
+    }
+
+    #[test]
+    fn scale_about_center() {
+        let center = Point::new(0.5, 0.5);
+        let scaled = scale_about(&unit_square(), &center, 2.0);
+        assert_eq!(
+            scaled,
+            vec![
+                Point::new(-0.5, -0.5),
+                Point::new(1.5, -0.5),
+                Point::new(1.5, 1.5),
+                Point::new(-0.5, 1.5),
+            ]
+        );
+        assert_eq!(
+            Affine2::scale_about(&center, 2.0).transform_point(&center),
+            center
+        );
+    }
+
+    #[test]
+    fn composition_order() {
+        let rotate = Affine2::rotate(std::f64::consts::FRAC_PI_2);
+        let translate = Affine2::translate(2.0, 0.0);
+        let p = Point::new(1.0, 0.0);
+
+        // Rotate first: (1, 0) -> (0, 1) -> (2, 1).
+        let rotate_then_translate = translate * rotate;
+        assert!(points_equal(
+            &rotate_then_translate.transform_point(&p),
+            &Point::new(2.0, 1.0)
+        ));
+
+        // Translate first: (1, 0) -> (3, 0) -> (0, 3).
+        let translate_then_rotate = rotate * translate;
+        assert!(points_equal(
+            &translate_then_rotate.transform_point(&p),
+            &Point::new(0.0, 3.0)
+        ));
+
+        let scale = Affine2::scale(2.0, -1.0);
+        assert_eq!(scale.transform_point(&p), Point::new(2.0, 0.0));
+        assert_eq!(Affine2::identity() * scale, scale);
+        assert_eq!(Affine2::<f64>::default(), Affine2::identity());
+    }
+}
@@ -0,0 +1,289 @@
+use crate::{
+    bounding_box, edges, line_parameter, point_in_polygon, points_within, polygon_signed_area,
+    segment_intersection_with_options, CutMode, Intersection, Line, Point, Scalar, SliceOptions,
+};
+
+/// Regions the polygon is divided into by all cut lines at once.
+/// Unlike `get_all_fragments` the order of the lines doesn't matter, and lines
+/// that only end on other lines (e.g. a T junction) still separate regions.
+/// Each region is returned counter-clockwise.
+///
+/// Lines that don't connect to the polygon boundary through other lines
+/// can outline a region of their own, but aren't cut out of the region around them.
+pub fn arrangement_regions<T: Scalar>(
+    points: &[Point<T>],
+    lines: &[Line<T>],
+) -> Vec<Vec<Point<T>>> {
+    arrangement_regions_with_options(points, lines, &SliceOptions::default())
+}
+
+/// Same as `arrangement_regions` but with custom options.
+pub fn arrangement_regions_with_options<T: Scalar>(
+    points: &[Point<T>],
+    lines: &[Line<T>],
+    options: &SliceOptions<T>,
+) -> Vec<Vec<Point<T>>> {
+    let epsilon = options.epsilon;
+    if points.len() < 3 {
+        return Vec::new();
+    }
+
+    let boundary: Vec<Line<T>> = edges(points).collect();
+    let cuts: Vec<Line<T>> = lines
+        .iter()
+        .filter_map(|line| reach_across(points, line, options.cut_mode))
+        .collect();
+    let segments: Vec<&Line<T>> = boundary.iter().chain(&cuts).collect();
+
+    // Split every segment wherever another one meets it.
+    let mut graph = Graph {
+        vertices: Vec::new(),
+        neighbours: Vec::new(),
+    };
+    for (index, segment) in segments.iter().enumerate() {
+        let mut stops = vec![T::ZERO, T::ONE];
+        for (other_index, other) in segments.iter().enumerate() {
+            if other_index == index {
+                continue;
+            }
+            match segment_intersection_with_options(segment, other, options) {
+                Intersection::None => {}
+                Intersection::Point(p) => stops.push(line_parameter(segment, &p)),
+                Intersection::Collinear(start, end) => {
+                    stops.push(line_parameter(segment, &start));
+                    stops.push(line_parameter(segment, &end));
+                }
+            }
+        }
+        stops.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+
+        let is_cut = index >= boundary.len();
+        for pair in stops.windows(2) {
+            let start = segment.point_at(pair[0].max(T::ZERO).min(T::ONE));
+            let end = segment.point_at(pair[1].max(T::ZERO).min(T::ONE));
+            if points_within(&start, &end, epsilon) {
+                continue;
+            }
+            // Pieces of a cut outside a concave polygon don't divide anything.
+            if is_cut
+                && !point_in_polygon(points, &Line::new(start, end).point_at(T::from_f64(0.5)))
+            {
+                continue;
+            }
+            graph.add_edge(start, end, epsilon);
+        }
+    }
+
+    graph.remove_dangling_edges();
+    graph
+        .faces()
+        .into_iter()
+        .filter(|face| polygon_signed_area(face) > epsilon)
+        .collect()
+}
+
+/// The part of the cut line that matters for the polygon, as a segment.
+/// Infinite lines and rays are cut off beyond the polygon's bounding box.
+fn reach_across<T: Scalar>(
+    points: &[Point<T>],
+    line: &Line<T>,
+    cut_mode: CutMode,
+) -> Option<Line<T>> {
+    let length = line.length();
+    if length == T::ZERO {
+        return None;
+    }
+    let (min, max) = bounding_box(points)?;
+    let direction = (line.p2 - line.p1) / length;
+    let center = (min + max) / T::from_f64(2.0);
+    let reach = line.p1.distance(&center) + min.distance(&max);
+
+    match cut_mode {
+        CutMode::Segment => Some(line.clone()),
+        CutMode::Line => Some(Line::new(
+            line.p1 - direction * reach,
+            line.p1 + direction * reach,
+        )),
+        CutMode::Ray => Some(Line::new(line.p1, line.p1 + direction * reach)),
+    }
+}
+
+/// Planar graph with the vertices merged within the tolerance.
+struct Graph<T> {
+    vertices: Vec<Point<T>>,
+    neighbours: Vec<Vec<usize>>,
+}
+
+impl<T: Scalar> Graph<T> {
+    fn vertex(&mut self, p: Point<T>, epsilon: T) -> usize {
+        if let Some(index) = self
+            .vertices
+            .iter()
+            .position(|v| points_within(v, &p, epsilon))
+        {
+            return index;
+        }
+        self.vertices.push(p);
+        self.neighbours.push(Vec::new());
+        self.vertices.len() - 1
+    }
+
+    fn add_edge(&mut self, a: Point<T>, b: Point<T>, epsilon: T) {
+        let a = self.vertex(a, epsilon);
+        let b = self.vertex(b, epsilon);
+        if a != b && !self.neighbours[a].contains(&b) {
+            self.neighbours[a].push(b);
+            self.neighbours[b].push(a);
+        }
+    }
+
+    /// Edges leading to a dead end can't be part of any region's outline.
+    fn remove_dangling_edges(&mut self) {
+        let mut dead_ends: Vec<usize> = (0..self.vertices.len())
+            .filter(|v| self.neighbours[*v].len() == 1)
+            .collect();
+        while let Some(v) = dead_ends.pop() {
+            if let Some(other) = self.neighbours[v].pop() {
+                self.neighbours[other].retain(|n| *n != v);
+                if self.neighbours[other].len() == 1 {
+                    dead_ends.push(other);
+                }
+            }
+        }
+    }
+
+    /// Traces every face by keeping it on the left of each edge walked,
+    /// turning onto the next edge clockwise at each vertex.
+    /// Bounded faces come out counter-clockwise, the outer face clockwise.
+    fn faces(&mut self) -> Vec<Vec<Point<T>>> {
+        // Neighbours in counter-clockwise order around each vertex.
+        for v in 0..self.vertices.len() {
+            let center = self.vertices[v];
+            let angle = |n: &usize| {
+                let d = self.vertices[*n] - center;
+                d.y.to_f64().atan2(d.x.to_f64())
+            };
+            let mut sorted = self.neighbours[v].clone();
+            sorted.sort_by(|a, b| angle(a).total_cmp(&angle(b)));
+            self.neighbours[v] = sorted;
+        }
+
+        let mut visited: Vec<Vec<bool>> = self
+            .neighbours
+            .iter()
+            .map(|n| vec![false; n.len()])
+            .collect();
+        let mut faces = Vec::new();
+        for start in 0..self.vertices.len() {
+            for start_slot in 0..self.neighbours[start].len() {
+                if visited[start][start_slot] {
+                    continue;
+                }
+
+                let mut face = Vec::new();
+                let (mut from, mut slot) = (start, start_slot);
+                while !visited[from][slot] {
+                    visited[from][slot] = true;
+                    face.push(self.vertices[from]);
+
+                    let to = self.neighbours[from][slot];
+                    let back = self.neighbours[to]
+                        .iter()
+                        .position(|n| *n == from)
+                        .expect("edges are stored in both directions");
+                    let count = self.neighbours[to].len();
+                    slot = (back + count - 1) % count;
+                    from = to;
+                }
+                faces.push(face);
+            }
+        }
+        faces
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::arrangement_regions;
+    use crate::{get_all_fragments, polygon_area, Line, Point};
+
+    fn square() -> Vec<Point> {
+        vec![
+            Point::new(0.0, 0.0),
+            Point::new(2.0, 0.0),
+            Point::new(2.0, 2.0),
+            Point::new(0.0, 2.0),
+        ]
+    }
+
+    /// Counts vertices and edges from the region outlines and checks
+    /// V - E + F = 2, counting the face outside the polygon as well.
+    fn assert_euler(regions: &[Vec<Point>]) {
+        let mut vertices: Vec<Point> = Vec::new();
+        let mut edges: Vec<(Point, Point)> = Vec::new();
+        for region in regions {
+            for (i, a) in region.iter().enumerate() {
+                let b = region[(i + 1) % region.len()];
+                if !vertices.contains(a) {
+                    vertices.push(*a);
+                }
+                if !edges.contains(&(*a, b)) && !edges.contains(&(b, *a)) {
+                    edges.push((*a, b));
+                }
+            }
+        }
+        let euler = vertices.len() as i64 - edges.len() as i64 + regions.len() as i64 + 1;
+        assert_eq!(euler, 2);
+    }
+
+    #[test]
+    fn perpendicular_lines_make_quarters() {
+        let lines = vec![
+            Line::from_coords(1.0, -1.0, 1.0, 3.0),
+            Line::from_coords(-1.0, 1.0, 3.0, 1.0),
+        ];
+        let regions = arrangement_regions(&square(), &lines);
+        assert_eq!(regions.len(), 4);
+        assert!(regions.iter().all(|r| polygon_area(r) == 1.0));
+        assert_euler(&regions);
+    }
+
+    #[test]
+    fn line_order_does_not_matter() {
+        // The short line only reaches from the long one to the top edge,
+        // so cutting in this order misses it.
+        let lines = vec![
+            Line::from_coords(1.0, 1.0, 1.0, 3.0),
+            Line::from_coords(-1.0, 1.0, 3.0, 1.0),
+        ];
+        assert_eq!(get_all_fragments(&square(), &lines).unwrap().len(), 2);
+
+        let regions = arrangement_regions(&square(), &lines);
+        let mut areas: Vec<f64> = regions.iter().map(|r| polygon_area(r)).collect();
+        areas.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        assert_eq!(areas, vec![1.0, 1.0, 2.0]);
+        assert_euler(&regions);
+
+        // A line ending inside a region doesn't divide it.
+        let dangling = vec![Line::from_coords(1.0, -1.0, 1.0, 1.0)];
+        let regions = arrangement_regions(&square(), &dangling);
+        assert_eq!(regions.len(), 1);
+        assert_eq!(polygon_area(&regions[0]), 4.0);
+    }
+
+    #[test]
+    fn pinwheel() {
+        // Four lines that each end on the next one, none crosses the square.
+        let lines = vec![
+            Line::from_coords(0.5, 0.0, 0.5, 1.5),
+            Line::from_coords(0.0, 1.5, 1.5, 1.5),
+            Line::from_coords(1.5, 2.0, 1.5, 0.5),
+            Line::from_coords(2.0, 0.5, 0.5, 0.5),
+        ];
+        let regions = arrangement_regions(&square(), &lines);
+        assert_eq!(regions.len(), 5);
+        let total: f64 = regions.iter().map(|r| polygon_area(r)).sum();
+        assert!((total - 4.0).abs() < 1e-12);
+        assert_euler(&regions);
+    }
+}
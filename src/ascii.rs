@@ -0,0 +1,85 @@
+use crate::{bounding_box, point_in_polygon, Point, Scalar};
+
+/// Characters the fragments are filled with, reused from the start
+/// when there are more fragments than characters.
+const FILL: &[u8] = b"#@%*+=o&x$~^";
+
+/// Draws fragments as a `width` x `height` grid of characters, one line per row,
+/// filling each fragment with its own character and leaving the rest blank.
+/// The grid is stretched over the bounding box of all fragments with the
+/// y axis pointing up, like the coordinates. Each cell takes the fragment
+/// its center lies in, so thin fragments can fall between the cells.
+pub fn render_ascii<T: Scalar>(fragments: &[Vec<Point<T>>], width: usize, height: usize) -> String {
+    let all_points: Vec<Point<T>> = fragments.concat();
+    let Some((min, max)) = bounding_box(&all_points) else {
+        return (" ".repeat(width) + "\n").repeat(height);
+    };
+    let (min_x, max_y) = (min.x.to_f64(), max.y.to_f64());
+    let cell_width = (max.x.to_f64() - min_x) / width as f64;
+    let cell_height = (max_y - min.y.to_f64()) / height as f64;
+
+    let mut ascii = String::with_capacity((width + 1) * height);
+    for row in 0..height {
+        for column in 0..width {
+            let center = Point::new(
+                T::from_f64(min_x + (column as f64 + 0.5) * cell_width),
+                T::from_f64(max_y - (row as f64 + 0.5) * cell_height),
+            );
+            let cell = fragments
+                .iter()
+                .position(|fragment| point_in_polygon(fragment, &center))
+                .map_or(' ', |index| FILL[index % FILL.len()] as char);
+            ascii.push(cell);
+        }
+        ascii.push('\n');
+    }
+    ascii
+}
+
+#[cfg(test)]
+mod tests {
+    use super::render_ascii;
+    use crate::{get_all_fragments, Line, Point};
+
+    #[test]
+    fn quartered_square() {
+        let polygon_points = vec![
+            Point::new(0.0, 0.0),
+            Point::new(1.0, 0.0),
+            Point::new(1.0, 1.0),
+            Point::new(0.0, 1.0),
+        ];
+        let lines = vec![
+            Line::from_coords(0.5, -1.0, 0.5, 2.0),
+            Line::from_coords(-1.0, 0.5, 2.0, 0.5),
+        ];
+        let fragments = get_all_fragments(&polygon_points, &lines).unwrap();
+
+        let ascii = render_ascii(&fragments, 8, 4);
+        let rows: Vec<&str> = ascii.lines().collect();
+        assert_eq!(rows.len(), 4);
+        assert!(rows.iter().all(|row| row.len() == 8));
+
+        let mut fills: Vec<char> = ascii.chars().filter(|c| *c != '\n').collect();
+        fills.sort();
+        fills.dedup();
+        assert_eq!(fills.len(), 4);
+        assert!(!fills.contains(&' '));
+
+        // Each quarter is a 4 x 2 block of its own character.
+        assert_eq!(&rows[0][..4], &rows[1][..4]);
+        assert_ne!(&rows[0][..4], &rows[0][4..]);
+        assert_ne!(&rows[0][..4], &rows[2][..4]);
+    }
+
+    #[test]
+    fn triangle_leaves_blank_cells() {
+        let triangle = vec![
+            Point::new(0.0, 0.0),
+            Point::new(4.0, 0.0),
+            Point::new(0.0, 4.0),
+        ];
+        assert_eq!(render_ascii(&[triangle], 4, 4), "#   \n##  \n### \n####\n");
+        assert_eq!(render_ascii::<f64>(&[], 2, 1), "  \n");
+    }
+}
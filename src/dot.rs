@@ -0,0 +1,59 @@
+use std::fmt::Write;
+
+use crate::{fragment_adjacency, polygon_area, Point, Scalar};
+
+/// Renders the fragments and their neighbours as a Graphviz DOT document,
+/// one node per fragment labelled with its index and area, and an edge
+/// between every pair from `fragment_adjacency`.
+/// Render it with e.g. `dot -Tpng regions.dot -o regions.png`.
+pub fn fragments_to_dot<T: Scalar>(fragments: &[Vec<Point<T>>]) -> String {
+    let mut dot = String::from("graph fragments {\n");
+    for (index, fragment) in fragments.iter().enumerate() {
+        let _ = writeln!(
+            dot,
+            "  {} [label=\"#{}\\narea {}\"];",
+            index,
+            index,
+            polygon_area(fragment)
+        );
+    }
+    for (a, b) in fragment_adjacency(fragments) {
+        let _ = writeln!(dot, "  {} -- {};", a, b);
+    }
+    dot.push_str("}\n");
+    dot
+}
+
+#[cfg(test)]
+mod tests {
+    use super::fragments_to_dot;
+    use crate::{get_all_fragments, Line, Point};
+
+    #[test]
+    fn quartered_square() {
+        let polygon_points = vec![
+            Point::new(0.0, 0.0),
+            Point::new(2.0, 0.0),
+            Point::new(2.0, 2.0),
+            Point::new(0.0, 2.0),
+        ];
+        let lines = vec![
+            Line::from_coords(1.0, -1.0, 1.0, 3.0),
+            Line::from_coords(-1.0, 1.0, 3.0, 1.0),
+        ];
+        let fragments = get_all_fragments(&polygon_points, &lines).unwrap();
+
+        let dot = fragments_to_dot(&fragments);
+        assert!(dot.starts_with("graph fragments {\n"));
+        assert!(dot.ends_with("}\n"));
+        assert_eq!(dot.matches("[label=").count(), 4);
+        assert_eq!(dot.matches("area 1\"").count(), 4);
+        // Each quarter borders two others, the diagonal ones only touch in the middle.
+        assert_eq!(dot.matches(" -- ").count(), 4);
+    }
+
+    #[test]
+    fn empty_input() {
+        assert_eq!(fragments_to_dot::<f64>(&[]), "graph fragments {\n}\n");
+    }
+}
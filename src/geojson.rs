@@ -0,0 +1,203 @@
+use std::error::Error;
+use std::fmt;
+
+use crate::json::{self, JsonError, JsonValue};
+use crate::{points_equal, Point, Polygon};
+
+/// Error produced when a GeoJSON document can't be read.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GeoJsonError {
+    pub message: String,
+}
+
+impl fmt::Display for GeoJsonError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl Error for GeoJsonError {}
+
+impl From<JsonError> for GeoJsonError {
+    fn from(error: JsonError) -> Self {
+        GeoJsonError {
+            message: error.to_string(),
+        }
+    }
+}
+
+fn geojson_error(message: &str) -> GeoJsonError {
+    GeoJsonError {
+        message: message.to_string(),
+    }
+}
+
+/// Reads every `Polygon` and `MultiPolygon` from a GeoJSON document.
+/// The document can be a bare geometry, a `Feature` or a `FeatureCollection`;
+/// features with other geometry types are skipped.
+/// The first ring of each polygon becomes the exterior and the remaining rings
+/// become holes. Positions are `[lon, lat]`, which map to `(x, y)`.
+pub fn from_geojson(json: &str) -> Result<Vec<Polygon>, GeoJsonError> {
+    let document = json::parse(json)?;
+    let mut polygons = Vec::new();
+    read_object(&document, &mut polygons)?;
+    Ok(polygons)
+}
+
+fn read_object(object: &JsonValue, polygons: &mut Vec<Polygon>) -> Result<(), GeoJsonError> {
+    let object_type = object
+        .get("type")
+        .and_then(JsonValue::as_str)
+        .ok_or_else(|| geojson_error("Missing \"type\""))?;
+
+    match object_type {
+        "FeatureCollection" => {
+            let features = object
+                .get("features")
+                .and_then(JsonValue::as_array)
+                .ok_or_else(|| geojson_error("Missing \"features\" array"))?;
+            for feature in features {
+                read_object(feature, polygons)?;
+            }
+        }
+        "Feature" => match object.get("geometry") {
+            Some(JsonValue::Null) | None => {}
+            Some(geometry) => read_object(geometry, polygons)?,
+        },
+        "Polygon" => polygons.push(read_polygon(coordinates(object)?)?),
+        "MultiPolygon" => {
+            let members = coordinates(object)?
+                .as_array()
+                .ok_or_else(|| geojson_error("MultiPolygon coordinates must be an array"))?;
+            for member in members {
+                polygons.push(read_polygon(member)?);
+            }
+        }
+        _ => {}
+    }
+
+    Ok(())
+}
+
+fn coordinates(geometry: &JsonValue) -> Result<&JsonValue, GeoJsonError> {
+    geometry
+        .get("coordinates")
+        .ok_or_else(|| geojson_error("Missing \"coordinates\""))
+}
+
+fn read_polygon(rings: &JsonValue) -> Result<Polygon, GeoJsonError> {
+    let mut rings = rings
+        .as_array()
+        .ok_or_else(|| geojson_error("Polygon coordinates must be an array of rings"))?
+        .iter()
+        .map(read_ring)
+        .collect::<Result<Vec<Vec<Point>>, GeoJsonError>>()?;
+
+    if rings.is_empty() {
+        return Err(geojson_error("Polygon has no rings"));
+    }
+
+    let exterior = rings.remove(0);
+    Ok(Polygon::with_holes(exterior, rings))
+}
+
+/// Reads a linear ring, dropping the closing position that repeats the first one.
+fn read_ring(ring: &JsonValue) -> Result<Vec<Point>, GeoJsonError> {
+    let mut points = Vec::new();
+    for position in ring
+        .as_array()
+        .ok_or_else(|| geojson_error("Ring must be an array of positions"))?
+    {
+        // Positions may carry an altitude as a third value, which is ignored.
+        let point = match position.as_array().map(Vec::as_slice) {
+            Some([x, y, ..]) => x.as_f64().zip(y.as_f64()).map(|(x, y)| Point::new(x, y)),
+            _ => None,
+        };
+        points.push(point.ok_or_else(|| geojson_error("Positions must be [lon, lat] arrays"))?);
+    }
+
+    if points.len() > 1 && points_equal(&points[0], &points[points.len() - 1]) {
+        points.pop();
+    }
+
+    Ok(points)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::from_geojson;
+    use crate::Point;
+
+    #[test]
+    fn polygon_with_hole() {
+        let json = r#"{
+            "type": "Polygon",
+            "coordinates": [
+                [[0, 0], [4, 0], [4, 4], [0, 4], [0, 0]],
+                [[1, 1], [1, 3], [3, 3], [3, 1], [1, 1]]
+            ]
+        }"#;
+
+        let polygons = from_geojson(json).unwrap();
+        assert_eq!(polygons.len(), 1);
+        assert_eq!(polygons[0].exterior.len(), 4);
+        assert_eq!(polygons[0].holes.len(), 1);
+        assert_eq!(polygons[0].holes[0][1], Point::new(1.0, 3.0));
+        assert_eq!(polygons[0].net_area(), 12.0);
+    }
+
+    #[test]
+    fn feature_collection_with_multi_polygon() {
+        let json = r#"{
+            "type": "FeatureCollection",
+            "features": [
+                {
+                    "type": "Feature",
+                    "properties": { "name": "squares" },
+                    "geometry": {
+                        "type": "MultiPolygon",
+                        "coordinates": [
+                            [[[0, 0], [1, 0], [1, 1], [0, 1], [0, 0]]],
+                            [[[2, 0], [3, 0], [3, 1], [2, 1], [2, 0]]]
+                        ]
+                    }
+                },
+                {
+                    "type": "Feature",
+                    "properties": {},
+                    "geometry": { "type": "Point", "coordinates": [5, 5] }
+                }
+            ]
+        }"#;
+
+        let polygons = from_geojson(json).unwrap();
+        assert_eq!(polygons.len(), 2);
+        assert_eq!(polygons[1].exterior[0], Point::new(2.0, 0.0));
+        assert!(polygons.iter().all(|polygon| polygon.holes.is_empty()));
+    }
+
+    #[test]
+    fn escaped_properties() {
+        // As written by exporters that escape everything outside ASCII.
+        let json = r#"{
+            "type": "Feature",
+            "properties": { "name": "Caf\u00e9 \u00e0 Z\u00fcrich \ud83d\uddfa" },
+            "geometry": {
+                "type": "Polygon",
+                "coordinates": [[[0, 0], [1, 0], [1, 1], [0, 0]]]
+            }
+        }"#;
+
+        let polygons = from_geojson(json).unwrap();
+        assert_eq!(polygons.len(), 1);
+        assert_eq!(polygons[0].exterior.len(), 3);
+    }
+
+    #[test]
+    fn invalid_documents() {
+        assert!(from_geojson("{").is_err());
+        assert!(from_geojson(r#"{ "coordinates": [] }"#).is_err());
+        assert!(from_geojson(r#"{ "type": "Polygon", "coordinates": [] }"#).is_err());
+        assert!(from_geojson(r#"{ "type": "Polygon", "coordinates": [[[0]]] }"#).is_err());
+    }
+}
@@ -0,0 +1,425 @@
+use std::error::Error;
+use std::fmt;
+
+use crate::{Line, Point};
+
+/// Error produced when a JSON document can't be read.
+#[derive(Debug, Clone, PartialEq)]
+pub struct JsonError {
+    pub message: String,
+    /// Byte offset into the input where the problem was found.
+    /// None when the document is valid JSON but doesn't have the expected shape.
+    pub position: Option<usize>,
+}
+
+impl fmt::Display for JsonError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.position {
+            Some(position) => write!(f, "{} at byte {}", self.message, position),
+            None => write!(f, "{}", self.message),
+        }
+    }
+}
+
+impl Error for JsonError {}
+
+/// Parsed JSON value.
+/// Only meant for the small input documents this crate reads,
+/// so objects are kept as a plain list of key/value pairs.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum JsonValue {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(String),
+    Array(Vec<JsonValue>),
+    Object(Vec<(String, JsonValue)>),
+}
+
+impl JsonValue {
+    pub(crate) fn get(&self, key: &str) -> Option<&JsonValue> {
+        match self {
+            JsonValue::Object(entries) => entries
+                .iter()
+                .find(|(name, _)| name == key)
+                .map(|(_, value)| value),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn as_array(&self) -> Option<&Vec<JsonValue>> {
+        match self {
+            JsonValue::Array(values) => Some(values),
+            _ => None,
+        }
+    }
+
+    #[cfg_attr(not(feature = "geojson"), allow(dead_code))]
+    pub(crate) fn as_str(&self) -> Option<&str> {
+        match self {
+            JsonValue::String(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn as_f64(&self) -> Option<f64> {
+        match self {
+            JsonValue::Number(value) => Some(*value),
+            _ => None,
+        }
+    }
+}
+
+/// Parses a complete JSON document.
+pub(crate) fn parse(input: &str) -> Result<JsonValue, JsonError> {
+    let mut parser = Parser {
+        bytes: input.as_bytes(),
+        position: 0,
+        depth: 0,
+    };
+    let value = parser.value()?;
+    parser.skip_whitespace();
+    if parser.position != parser.bytes.len() {
+        return Err(parser.error("Trailing characters"));
+    }
+    Ok(value)
+}
+
+/// Deepest nesting of arrays and objects accepted, so that deeply nested
+/// input is reported as an error instead of overflowing the stack.
+const MAX_DEPTH: usize = 128;
+
+struct Parser<'a> {
+    bytes: &'a [u8],
+    position: usize,
+    /// Number of arrays and objects currently open.
+    depth: usize,
+}
+
+impl Parser<'_> {
+    fn error(&self, message: &str) -> JsonError {
+        JsonError {
+            message: message.to_string(),
+            position: Some(self.position),
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(b' ' | b'\t' | b'\n' | b'\r') = self.bytes.get(self.position) {
+            self.position += 1;
+        }
+    }
+
+    fn peek(&mut self) -> Option<u8> {
+        self.skip_whitespace();
+        self.bytes.get(self.position).copied()
+    }
+
+    fn expect(&mut self, byte: u8) -> Result<(), JsonError> {
+        if self.peek() == Some(byte) {
+            self.position += 1;
+            Ok(())
+        } else {
+            Err(self.error(&format!("Expected '{}'", byte as char)))
+        }
+    }
+
+    fn literal(&mut self, text: &str, value: JsonValue) -> Result<JsonValue, JsonError> {
+        if self.bytes[self.position..].starts_with(text.as_bytes()) {
+            self.position += text.len();
+            Ok(value)
+        } else {
+            Err(self.error("Unexpected character"))
+        }
+    }
+
+    fn value(&mut self) -> Result<JsonValue, JsonError> {
+        match self.peek() {
+            Some(b'{' | b'[') => {
+                if self.depth == MAX_DEPTH {
+                    return Err(self.error("Nested too deeply"));
+                }
+                self.depth += 1;
+                let value = if self.bytes[self.position] == b'{' {
+                    self.object()
+                } else {
+                    self.array()
+                };
+                self.depth -= 1;
+                value
+            }
+            Some(b'"') => Ok(JsonValue::String(self.string()?)),
+            Some(b't') => self.literal("true", JsonValue::Bool(true)),
+            Some(b'f') => self.literal("false", JsonValue::Bool(false)),
+            Some(b'n') => self.literal("null", JsonValue::Null),
+            Some(b'-' | b'0'..=b'9') => self.number(),
+            Some(_) => Err(self.error("Unexpected character")),
+            None => Err(self.error("Unexpected end of input")),
+        }
+    }
+
+    fn object(&mut self) -> Result<JsonValue, JsonError> {
+        self.expect(b'{')?;
+        let mut entries = Vec::new();
+        if self.peek() == Some(b'}') {
+            self.position += 1;
+            return Ok(JsonValue::Object(entries));
+        }
+
+        loop {
+            if self.peek() != Some(b'"') {
+                return Err(self.error("Expected object key"));
+            }
+            let key = self.string()?;
+            self.expect(b':')?;
+            entries.push((key, self.value()?));
+
+            match self.peek() {
+                Some(b',') => self.position += 1,
+                Some(b'}') => {
+                    self.position += 1;
+                    return Ok(JsonValue::Object(entries));
+                }
+                _ => return Err(self.error("Expected ',' or '}'")),
+            }
+        }
+    }
+
+    fn array(&mut self) -> Result<JsonValue, JsonError> {
+        self.expect(b'[')?;
+        let mut values = Vec::new();
+        if self.peek() == Some(b']') {
+            self.position += 1;
+            return Ok(JsonValue::Array(values));
+        }
+
+        loop {
+            values.push(self.value()?);
+
+            match self.peek() {
+                Some(b',') => self.position += 1,
+                Some(b']') => {
+                    self.position += 1;
+                    return Ok(JsonValue::Array(values));
+                }
+                _ => return Err(self.error("Expected ',' or ']'")),
+            }
+        }
+    }
+
+    fn string(&mut self) -> Result<String, JsonError> {
+        self.expect(b'"')?;
+        let mut text = String::new();
+        loop {
+            let start = self.position;
+            while let Some(byte) = self.bytes.get(self.position) {
+                if *byte == b'"' || *byte == b'\\' {
+                    break;
+                }
+                self.position += 1;
+            }
+            // Only ASCII bytes stop the scan above, so this is on a char boundary.
+            text.push_str(std::str::from_utf8(&self.bytes[start..self.position]).unwrap());
+
+            match self.bytes.get(self.position) {
+                Some(b'"') => {
+                    self.position += 1;
+                    return Ok(text);
+                }
+                Some(b'\\') => {
+                    self.position += 1;
+                    let escaped = match self.bytes.get(self.position) {
+                        Some(b'"') => '"',
+                        Some(b'\\') => '\\',
+                        Some(b'/') => '/',
+                        Some(b'n') => '\n',
+                        Some(b't') => '\t',
+                        Some(b'r') => '\r',
+                        Some(b'b') => '\u{8}',
+                        Some(b'f') => '\u{c}',
+                        Some(b'u') => {
+                            self.position += 1;
+                            text.push(self.unicode_escape()?);
+                            continue;
+                        }
+                        _ => return Err(self.error("Unsupported escape sequence")),
+                    };
+                    text.push(escaped);
+                    self.position += 1;
+                }
+                _ => return Err(self.error("Unterminated string")),
+            }
+        }
+    }
+
+    /// The character of a `\uXXXX` escape, just after the `u`. Characters outside
+    /// the Basic Multilingual Plane are written as a pair of surrogate escapes.
+    fn unicode_escape(&mut self) -> Result<char, JsonError> {
+        let start = self.position;
+        let invalid = || JsonError {
+            message: "Invalid unicode escape".to_string(),
+            position: Some(start),
+        };
+
+        let high = self.hex4().ok_or_else(invalid)?;
+        let code = match high {
+            0xD800..=0xDBFF => {
+                if !self.bytes[self.position..].starts_with(b"\\u") {
+                    return Err(invalid());
+                }
+                self.position += 2;
+                let low = self.hex4().ok_or_else(invalid)?;
+                if !(0xDC00..=0xDFFF).contains(&low) {
+                    return Err(invalid());
+                }
+                0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00)
+            }
+            _ => high,
+        };
+        char::from_u32(code).ok_or_else(invalid)
+    }
+
+    /// Four hex digits as a number.
+    fn hex4(&mut self) -> Option<u32> {
+        let digits = self.bytes.get(self.position..self.position + 4)?;
+        let code = u32::from_str_radix(std::str::from_utf8(digits).ok()?, 16).ok()?;
+        self.position += 4;
+        Some(code)
+    }
+
+    fn number(&mut self) -> Result<JsonValue, JsonError> {
+        let start = self.position;
+        while let Some(b'-' | b'+' | b'.' | b'e' | b'E' | b'0'..=b'9') =
+            self.bytes.get(self.position)
+        {
+            self.position += 1;
+        }
+
+        std::str::from_utf8(&self.bytes[start..self.position])
+            .ok()
+            .and_then(|text| text.parse().ok())
+            .map(JsonValue::Number)
+            .ok_or_else(|| JsonError {
+                message: "Invalid number".to_string(),
+                position: Some(start),
+            })
+    }
+}
+
+fn point_from_json(value: &JsonValue) -> Option<Point> {
+    match value.as_array()?.as_slice() {
+        [x, y] => Some(Point::new(x.as_f64()?, y.as_f64()?)),
+        _ => None,
+    }
+}
+
+fn line_from_json(value: &JsonValue) -> Option<Line> {
+    match value.as_array()?.as_slice() {
+        [p1, p2] => Some(Line::new(point_from_json(p1)?, point_from_json(p2)?)),
+        _ => None,
+    }
+}
+
+/// Reads a polygon and its cut lines from a JSON document shaped like
+/// `{ "polygon": [[x, y], ...], "lines": [[[x1, y1], [x2, y2]], ...] }`.
+pub fn parse_input(json: &str) -> Result<(Vec<Point>, Vec<Line>), JsonError> {
+    let document = parse(json)?;
+    let invalid = |message: &str| JsonError {
+        message: message.to_string(),
+        position: None,
+    };
+
+    let polygon = document
+        .get("polygon")
+        .and_then(JsonValue::as_array)
+        .ok_or_else(|| invalid("Missing \"polygon\" array"))?
+        .iter()
+        .map(|value| point_from_json(value).ok_or_else(|| invalid("Points must be [x, y] pairs")))
+        .collect::<Result<Vec<Point>, JsonError>>()?;
+
+    let lines = document
+        .get("lines")
+        .and_then(JsonValue::as_array)
+        .ok_or_else(|| invalid("Missing \"lines\" array"))?
+        .iter()
+        .map(|value| {
+            line_from_json(value).ok_or_else(|| invalid("Lines must be [[x1, y1], [x2, y2]] pairs"))
+        })
+        .collect::<Result<Vec<Line>, JsonError>>()?;
+
+    Ok((polygon, lines))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, parse_input, JsonValue, MAX_DEPTH};
+    use crate::{Line, Point};
+
+    #[test]
+    fn parse_values() {
+        let value = parse(r#" { "a": [1, -2.5e1, true, null], "b": "x\"y" } "#).unwrap();
+        assert_eq!(
+            value.get("a"),
+            Some(&JsonValue::Array(vec![
+                JsonValue::Number(1.0),
+                JsonValue::Number(-25.0),
+                JsonValue::Bool(true),
+                JsonValue::Null,
+            ]))
+        );
+        assert_eq!(value.get("b"), Some(&JsonValue::String("x\"y".to_string())));
+
+        assert!(parse("[1, 2").is_err());
+        assert!(parse("[1, 2] 3").is_err());
+        assert_eq!(parse("{\"a\" 1}").unwrap_err().position, Some(5));
+    }
+
+    #[test]
+    fn unicode_escapes() {
+        assert_eq!(
+            parse(r#""caf\u00e9 \u00C9t\u00e9 \ud83d\ude00""#),
+            Ok(JsonValue::String("café Été 😀".to_string()))
+        );
+        // A lone surrogate, too few digits, or digits that aren't hex.
+        assert!(parse(r#""\ud83d""#).is_err());
+        assert!(parse(r#""\ud83d\u0041""#).is_err());
+        assert!(parse(r#""\u00e""#).is_err());
+        assert!(parse(r#""\u00zz""#).is_err());
+    }
+
+    #[test]
+    fn nesting_limit() {
+        let nested = |depth: usize| "[".repeat(depth) + &"]".repeat(depth);
+        assert!(parse(&nested(MAX_DEPTH)).is_ok());
+        let error = parse(&nested(MAX_DEPTH + 1)).unwrap_err();
+        assert_eq!(error.message, "Nested too deeply");
+
+        // Far too deep to recurse into, still an error.
+        assert!(parse_input(&"[".repeat(1_000_000)).is_err());
+    }
+
+    #[test]
+    fn sample_input() {
+        let json = r#"{
+            "polygon": [[0, 0], [1, 0], [1, 1], [0, 1]],
+            "lines": [[[0, 0], [1, 1]], [[0.5, 0], [0.5, 1]]]
+        }"#;
+
+        let (polygon, lines) = parse_input(json).unwrap();
+        assert_eq!(
+            polygon,
+            vec![
+                Point::new(0.0, 0.0),
+                Point::new(1.0, 0.0),
+                Point::new(1.0, 1.0),
+                Point::new(0.0, 1.0),
+            ]
+        );
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[1].p1, Line::from_coords(0.5, 0.0, 0.5, 1.0).p1);
+        assert_eq!(crate::get_largest_polygon_area(&polygon, &lines), Ok(0.375));
+
+        assert!(parse_input(r#"{ "polygon": [[0, 0]] }"#).is_err());
+        assert!(parse_input(r#"{ "polygon": [[0]], "lines": [] }"#).is_err());
+    }
+}
@@ -0,0 +1,5848 @@
+mod affine;
+mod arrangement;
+mod ascii;
+mod dot;
+#[cfg(feature = "geojson")]
+mod geojson;
+mod json;
+mod robust;
+mod scalar;
+mod scene;
+mod svg;
+#[cfg(any(test, feature = "testing"))]
+mod testing;
+#[cfg(feature = "wasm")]
+mod wasm;
+mod wkt;
+
+pub use affine::{rotate_about, scale_about, Affine2};
+pub use arrangement::{arrangement_regions, arrangement_regions_with_options};
+pub use ascii::render_ascii;
+pub use dot::fragments_to_dot;
+#[cfg(feature = "geojson")]
+pub use geojson::{from_geojson, GeoJsonError};
+pub use json::{parse_input, JsonError};
+pub use robust::orient2d;
+pub use scalar::Scalar;
+pub use scene::{Scene, SceneBuilder};
+pub use svg::fragments_to_svg;
+#[cfg(feature = "testing")]
+pub use testing::{random_convex_polygon, random_simple_polygon};
+#[cfg(feature = "wasm")]
+pub use wasm::{slice_fragments, slice_largest_area};
+pub use wkt::{parse_wkt_polygon, polygon_to_wkt, WktError};
+
+use std::collections::VecDeque;
+use std::fmt;
+use std::ops::{Add, Div, Mul, Sub};
+
+use robust::robust_cross;
+
+/// Default tolerance used when comparing `f64` values.
+/// See `Scalar::EPSILON` for the tolerance of other types.
+pub const EPSILON: f64 = 1e-10;
+
+/// Tuning knobs for the slicing functions.
+/// The plain functions (`split_polygon` etc.) use `SliceOptions::default()`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SliceOptions<T = f64> {
+    /// Tolerance for the floating point comparisons. Scale it with the
+    /// coordinates, e.g. `1e-4` for coordinates in the millions.
+    pub epsilon: T,
+    /// How far a cut line reaches, see `CutMode`.
+    pub cut_mode: CutMode,
+    /// Whether lines cut each other's pieces, see `SliceMode`.
+    pub slice_mode: SliceMode,
+}
+
+impl<T: Scalar> Default for SliceOptions<T> {
+    fn default() -> Self {
+        SliceOptions {
+            epsilon: T::EPSILON,
+            cut_mode: CutMode::default(),
+            slice_mode: SliceMode::default(),
+        }
+    }
+}
+
+/// How a cut `Line` is extended when slicing.
+/// Polygon edges are always treated as bounded segments.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CutMode {
+    /// Only the segment between `p1` and `p2` cuts.
+    #[default]
+    Segment,
+    /// The infinite line through `p1` and `p2` cuts.
+    Line,
+    /// The half-line starting at `p1` and passing through `p2` cuts.
+    Ray,
+}
+
+/// How a list of cut lines is applied to a polygon.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SliceMode {
+    /// Each line cuts the pieces left by the lines before it.
+    #[default]
+    Sequential,
+    /// Each line cuts the original polygon on its own and the pieces from
+    /// every line are returned together, overlapping each other.
+    Independent,
+}
+
+impl CutMode {
+    /// Whether the position `t` along the cut line, 0 at `p1` and 1 at `p2`,
+    /// is part of the cut.
+    fn contains<T: Scalar>(self, t: T, epsilon: T) -> bool {
+        match self {
+            CutMode::Segment => (-epsilon..=T::ONE + epsilon).contains(&t),
+            CutMode::Line => true,
+            CutMode::Ray => t >= -epsilon,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Point<T = f64> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T: Scalar> Point<T> {
+    pub fn new(x: T, y: T) -> Self {
+        Point { x, y }
+    }
+
+    /// Euclidean distance between the two points.
+    pub fn distance(&self, other: &Point<T>) -> T {
+        (other.x - self.x).hypot(other.y - self.y)
+    }
+
+    /// Squared distance, cheaper than `distance` when only comparing distances.
+    pub fn distance_squared(&self, other: &Point<T>) -> T {
+        let dx = other.x - self.x;
+        let dy = other.y - self.y;
+        dx * dx + dy * dy
+    }
+
+    /// Dot product, treating both points as vectors from the origin.
+    pub fn dot(&self, other: &Point<T>) -> T {
+        self.x * other.x + self.y * other.y
+    }
+
+    /// Z component of the cross product, treating both points as vectors
+    /// from the origin. Positive when `other` is counter-clockwise from `self`.
+    pub fn cross(&self, other: &Point<T>) -> T {
+        self.x * other.y - self.y * other.x
+    }
+
+    /// The grid cell of size `cell` the point falls in, see `QuantizedPoint`.
+    pub fn quantized(&self, cell: T) -> QuantizedPoint {
+        QuantizedPoint {
+            x: (self.x / cell).round().to_f64() as i64,
+            y: (self.y / cell).round().to_f64() as i64,
+        }
+    }
+}
+
+/// A point snapped to a grid, as whole multiples of the cell size, which
+/// unlike `Point` can be compared with `Eq` and used as a `HashMap` key.
+///
+/// Points closer than a cell mostly land in the same cell, but two points
+/// on either side of a cell border never do, however close. A larger cell
+/// merges more of the nearly equal points and also more of the distinct ones.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct QuantizedPoint {
+    pub x: i64,
+    pub y: i64,
+}
+
+/// Point on the integer grid, for exact arithmetic in `polygon_area_exact`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Point2i {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Point2i {
+    pub fn new(x: i64, y: i64) -> Self {
+        Point2i { x, y }
+    }
+}
+
+impl From<QuantizedPoint> for Point2i {
+    fn from(point: QuantizedPoint) -> Self {
+        Point2i::new(point.x, point.y)
+    }
+}
+
+impl<T: Scalar> Add for Point<T> {
+    type Output = Point<T>;
+
+    fn add(self, other: Point<T>) -> Point<T> {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl<T: Scalar> Sub for Point<T> {
+    type Output = Point<T>;
+
+    fn sub(self, other: Point<T>) -> Point<T> {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl<T: Scalar> Mul<T> for Point<T> {
+    type Output = Point<T>;
+
+    fn mul(self, scale: T) -> Point<T> {
+        Point::new(self.x * scale, self.y * scale)
+    }
+}
+
+impl<T: Scalar> Div<T> for Point<T> {
+    type Output = Point<T>;
+
+    fn div(self, scale: T) -> Point<T> {
+        Point::new(self.x / scale, self.y / scale)
+    }
+}
+
+impl<T: Scalar> From<(T, T)> for Point<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Point::new(x, y)
+    }
+}
+
+impl<T: Scalar> From<[T; 2]> for Point<T> {
+    fn from([x, y]: [T; 2]) -> Self {
+        Point::new(x, y)
+    }
+}
+
+impl<T: Scalar> From<Point<T>> for (T, T) {
+    fn from(point: Point<T>) -> Self {
+        (point.x, point.y)
+    }
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Line<T = f64> {
+    pub p1: Point<T>,
+    pub p2: Point<T>,
+}
+
+impl<T: Scalar> Line<T> {
+    pub fn new(p1: Point<T>, p2: Point<T>) -> Self {
+        Line { p1, p2 }
+    }
+
+    /// Builds a line straight from the coordinates of its two end points.
+    pub fn from_coords(x1: T, y1: T, x2: T, y2: T) -> Self {
+        Line::new(Point::new(x1, y1), Point::new(x2, y2))
+    }
+
+    /// Line of the given length starting at `origin`, pointing `radians`
+    /// counter-clockwise from the positive x axis.
+    pub fn from_point_angle(origin: Point<T>, radians: T, length: T) -> Self {
+        let (sin, cos) = radians.sin_cos();
+        Line::new(origin, origin + Point::new(cos, sin) * length)
+    }
+
+    /// Line in Hough normal form, the points where `x cos(theta) + y sin(theta) = rho`.
+    /// That line is infinite, so this returns the unit long piece starting at
+    /// the point closest to the origin. Cut with `CutMode::Line` to use all of it.
+    pub fn from_rho_theta(rho: T, theta: T) -> Self {
+        let (sin, cos) = theta.sin_cos();
+        let foot = Point::new(cos, sin) * rho;
+        Line::new(foot, foot + Point::new(-sin, cos))
+    }
+
+    pub fn length(&self) -> T {
+        self.p1.distance(&self.p2)
+    }
+
+    /// Rise over run from `p1` to `p2`.
+    /// None for a vertical line, where the slope is undefined,
+    /// instead of an infinite or NaN value.
+    pub fn slope(&self) -> Option<T> {
+        let d = self.p2 - self.p1;
+        if d.x.abs() < T::EPSILON {
+            None
+        } else {
+            Some(d.y / d.x)
+        }
+    }
+
+    /// Direction from `p1` to `p2` in radians, counter-clockwise from the
+    /// positive x axis, in (-pi, pi]. Unlike `slope` this is defined for vertical lines.
+    pub fn angle(&self) -> T {
+        let d = self.p2 - self.p1;
+        d.y.atan2(d.x)
+    }
+
+    pub fn midpoint(&self) -> Point<T> {
+        self.point_at(T::from_f64(0.5))
+    }
+
+    /// Point along the line where `t = 0` is `p1` and `t = 1` is `p2`.
+    /// Values outside of [0, 1] extend the line past its end points.
+    pub fn point_at(&self, t: T) -> Point<T> {
+        self.p1 + (self.p2 - self.p1) * t
+    }
+
+    /// Whether the two segments have any point in common, without working out where.
+    /// As for `segment_intersection`, segments touching at an end point and
+    /// collinear segments that overlap count as intersecting, while zero length
+    /// lines never intersect. Unlike `segment_intersection` there is no
+    /// tolerance, only the exact signs of `orient2d` are used.
+    pub fn intersects(&self, other: &Line<T>) -> bool {
+        if self.p1 == self.p2 || other.p1 == other.p2 {
+            return false;
+        }
+
+        let sign = |value: T| (value > T::ZERO) as i8 - (value < T::ZERO) as i8;
+        let side_1 = sign(orient2d(&self.p1, &self.p2, &other.p1));
+        let side_2 = sign(orient2d(&self.p1, &self.p2, &other.p2));
+        let side_3 = sign(orient2d(&other.p1, &other.p2, &self.p1));
+        let side_4 = sign(orient2d(&other.p1, &other.p2, &self.p2));
+
+        // Both ends of one segment strictly on the same side of the other.
+        if side_1 * side_2 > 0 || side_3 * side_4 > 0 {
+            return false;
+        }
+        if side_1 != 0 || side_2 != 0 {
+            return true;
+        }
+
+        // On the same line, so they meet if their extents overlap on both axes.
+        let overlaps =
+            |a1: T, a2: T, b1: T, b2: T| a1.max(a2) >= b1.min(b2) && b1.max(b2) >= a1.min(a2);
+        overlaps(self.p1.x, self.p2.x, other.p1.x, other.p2.x)
+            && overlaps(self.p1.y, self.p2.y, other.p1.y, other.p2.y)
+    }
+}
+
+/// Edges of the polygon in order, including the closing edge
+/// from the last point back to the first.
+pub fn edges<T: Scalar>(points: &[Point<T>]) -> impl Iterator<Item = Line<T>> + '_ {
+    points
+        .iter()
+        .zip(points.iter().cycle().skip(1))
+        .map(|(current, next)| Line::new(*current, *next))
+}
+
+impl<T: Scalar> fmt::Display for Point<T> {
+    /// Formats as `(x, y)`, passing a precision like `{:.3}` on to both coordinates.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match f.precision() {
+            Some(precision) => write!(f, "({:.*}, {:.*})", precision, self.x, precision, self.y),
+            None => write!(f, "({}, {})", self.x, self.y),
+        }
+    }
+}
+
+impl<T: Scalar> fmt::Display for Line<T> {
+    /// Formats as `(x1, y1) -> (x2, y2)`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.p1, f)?;
+        write!(f, " -> ")?;
+        fmt::Display::fmt(&self.p2, f)
+    }
+}
+
+pub type PointF64 = Point<f64>;
+pub type PointF32 = Point<f32>;
+pub type LineF64 = Line<f64>;
+pub type LineF32 = Line<f32>;
+
+/// Compares two points within +-EPSILON on each axis.
+/// Use this instead of `==` on computed points, which can differ in the last bits.
+pub fn points_equal<T: Scalar>(a: &Point<T>, b: &Point<T>) -> bool {
+    points_within(a, b, T::EPSILON)
+}
+
+fn points_within<T: Scalar>(a: &Point<T>, b: &Point<T>, epsilon: T) -> bool {
+    (a.x - b.x).abs() < epsilon && (a.y - b.y).abs() < epsilon
+}
+
+/// The points without an explicit closing point, if the last one repeats
+/// the first within `epsilon` as in GeoJSON or WKT rings.
+fn open_ring<T: Scalar>(points: &[Point<T>], epsilon: T) -> &[Point<T>] {
+    match points {
+        [first, .., last] if points_within(first, last, epsilon) => &points[..points.len() - 1],
+        _ => points,
+    }
+}
+
+/// How two line segments meet.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Intersection<T = f64> {
+    /// The segments don't meet.
+    None,
+    /// The segments cross or touch in a single point.
+    Point(Point<T>),
+    /// The segments lie on the same line and overlap between the two points.
+    Collinear(Point<T>, Point<T>),
+}
+
+/// Line segment Line segment collision check
+/// https://en.wikipedia.org/wiki/Line%E2%80%93line_intersection
+/// Returns None for collinear segments that overlap in more than a single point,
+/// use `segment_intersection` to tell those apart from a miss.
+/// Returns None if either line has zero length (shorter than EPSILON), even when
+/// its point lies on the other line, since it has no direction to cross with.
+pub fn line_segment_intersection<T: Scalar>(line1: &Line<T>, line2: &Line<T>) -> Option<Point<T>> {
+    line_segment_intersection_with_options(line1, line2, &SliceOptions::default())
+}
+
+/// Same as `line_segment_intersection` but with custom options.
+pub fn line_segment_intersection_with_options<T: Scalar>(
+    line1: &Line<T>,
+    line2: &Line<T>,
+    options: &SliceOptions<T>,
+) -> Option<Point<T>> {
+    match segment_intersection_with_options(line1, line2, options) {
+        Intersection::Point(point) => Some(point),
+        Intersection::None | Intersection::Collinear(_, _) => None,
+    }
+}
+
+/// Line segment Line segment collision check that also reports
+/// the overlapping part of collinear segments.
+/// Zero length lines never intersect, as in `line_segment_intersection`.
+pub fn segment_intersection<T: Scalar>(line1: &Line<T>, line2: &Line<T>) -> Intersection<T> {
+    segment_intersection_with_options(line1, line2, &SliceOptions::default())
+}
+
+/// Same as `segment_intersection` but with custom options.
+pub fn segment_intersection_with_options<T: Scalar>(
+    line1: &Line<T>,
+    line2: &Line<T>,
+    options: &SliceOptions<T>,
+) -> Intersection<T> {
+    let epsilon = options.epsilon;
+    if line1.length() < epsilon || line2.length() < epsilon {
+        return Intersection::None;
+    }
+
+    // Without parameters the lines are parallel or coincident.
+    let Some((t, u)) = intersection_params_with_options(line1, line2, options) else {
+        return collinear_overlap(line1, line2, epsilon);
+    };
+
+    // If t and u are in the [0, 1] range with some tolerance we have an intersection.
+    let range = -epsilon..=T::ONE + epsilon;
+    if range.contains(&t) && range.contains(&u) {
+        Intersection::Point(line1.point_at(t))
+    } else {
+        Intersection::None
+    }
+}
+
+/// Where the infinite lines through the two segments meet, as `(t, u)` with
+/// the point at `line1.point_at(t)` and at `line2.point_at(u)`. Values in
+/// [0, 1] are on the segments themselves. None for parallel lines.
+pub fn intersection_params<T: Scalar>(line1: &Line<T>, line2: &Line<T>) -> Option<(T, T)> {
+    intersection_params_with_options(line1, line2, &SliceOptions::default())
+}
+
+/// Same as `intersection_params` but with custom options.
+pub fn intersection_params_with_options<T: Scalar>(
+    line1: &Line<T>,
+    line2: &Line<T>,
+    options: &SliceOptions<T>,
+) -> Option<(T, T)> {
+    let denom = robust_cross(&line1.p1, &line1.p2, &line2.p1, &line2.p2);
+    if denom.abs() < options.epsilon {
+        return None;
+    }
+
+    let offset = line2.p1 - line1.p1;
+    let t = offset.cross(&(line2.p2 - line2.p1)) / denom;
+    let u = offset.cross(&(line1.p2 - line1.p1)) / denom;
+    Some((t, u))
+}
+
+/// Overlap of two parallel segments, if they lie on the same line.
+fn collinear_overlap<T: Scalar>(line1: &Line<T>, line2: &Line<T>, epsilon: T) -> Intersection<T> {
+    let on_line = |p: &Point<T>| point_line_side(line1, p).abs() <= epsilon;
+    if !on_line(&line2.p1) || !on_line(&line2.p2) {
+        return Intersection::None;
+    }
+
+    // Clamp line2's extent along line1 to line1 itself.
+    let t1 = line_parameter(line1, &line2.p1);
+    let t2 = line_parameter(line1, &line2.p2);
+    let start = t1.min(t2).max(T::ZERO);
+    let end = t1.max(t2).min(T::ONE);
+
+    let length = line1.length();
+    if (end - start) * length < -epsilon {
+        Intersection::None
+    } else if (end - start) * length <= epsilon {
+        Intersection::Point(line1.point_at(start))
+    } else {
+        Intersection::Collinear(line1.point_at(start), line1.point_at(end))
+    }
+}
+
+/// Bounding box of a segment, grown so that it also covers the end point
+/// tolerance `line_segment_intersection` allows beyond each end.
+fn segment_bounds<T: Scalar>(a: &Point<T>, b: &Point<T>, epsilon: T) -> (Point<T>, Point<T>) {
+    let length = (b.x - a.x).abs() + (b.y - a.y).abs();
+    let margin = T::from_f64(2.0) * epsilon * (length + T::ONE);
+    (
+        Point::new(a.x.min(b.x) - margin, a.y.min(b.y) - margin),
+        Point::new(a.x.max(b.x) + margin, a.y.max(b.y) + margin),
+    )
+}
+
+/// Fins all intersections between a polygon and a line
+/// then removes duplicates within +-EPSILON floating point marginal.
+/// The points are returned in order from `line.p1` towards `line.p2`.
+/// A closing point repeating the first one is ignored.
+/// Non-finite coordinates can't be ordered, so they give no points at all.
+pub fn find_intersections<T: Scalar>(polygon_points: &[Point<T>], line: &Line<T>) -> Vec<Point<T>> {
+    find_intersections_with_options(polygon_points, line, &SliceOptions::default())
+}
+
+/// Same as `find_intersections` but with custom options.
+pub fn find_intersections_with_options<T: Scalar>(
+    polygon_points: &[Point<T>],
+    line: &Line<T>,
+    options: &SliceOptions<T>,
+) -> Vec<Point<T>> {
+    if validate_polygon(polygon_points).is_err() || validate_line(line).is_err() {
+        return Vec::new();
+    }
+    let polygon_points = open_ring(polygon_points, options.epsilon);
+    let mut intersection_points = Vec::new();
+    let (line_min, line_max) = segment_bounds(&line.p1, &line.p2, options.epsilon);
+
+    for (edge_index, edge_line) in edges(polygon_points).enumerate() {
+        // Cheap reject for edges that can't reach the cut segment.
+        let (edge_min, edge_max) = segment_bounds(&edge_line.p1, &edge_line.p2, options.epsilon);
+        if options.cut_mode == CutMode::Segment
+            && (edge_max.x < line_min.x
+                || edge_min.x > line_max.x
+                || edge_max.y < line_min.y
+                || edge_min.y > line_max.y)
+        {
+            continue;
+        }
+
+        if let Some(intersection_point) = cut_edge_intersection(line, &edge_line, options) {
+            intersection_points.push((edge_index, intersection_point));
+        }
+    }
+
+    // Order the points along the cut line so consecutive points pair up
+    // as (enter, exit) whatever the line's angle, then remove duplicates.
+    // A crossing through a vertex is found on both edges meeting there,
+    // points close together on edges further apart are separate crossings.
+    let edge_count = polygon_points.len();
+    let adjacent =
+        |a: usize, b: usize| a == b || (a + 1) % edge_count == b || (b + 1) % edge_count == a;
+    intersection_points.sort_by(|(_, a), (_, b)| {
+        line_parameter(line, a)
+            .partial_cmp(&line_parameter(line, b))
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+    intersection_points.dedup_by(|(edge_a, a), (edge_b, b)| {
+        adjacent(*edge_a, *edge_b) && points_within(a, b, options.epsilon)
+    });
+
+    intersection_points
+        .into_iter()
+        .map(|(_, point)| point)
+        .collect()
+}
+
+/// Intersection between a cut line and a polygon edge,
+/// extending the cut line as `options.cut_mode` says.
+fn cut_edge_intersection<T: Scalar>(
+    line: &Line<T>,
+    edge: &Line<T>,
+    options: &SliceOptions<T>,
+) -> Option<Point<T>> {
+    if options.cut_mode == CutMode::Segment {
+        return line_segment_intersection_with_options(line, edge, options);
+    }
+
+    let epsilon = options.epsilon;
+    let (t, u) = intersection_params_with_options(line, edge, options)?;
+    // Interpolate along the edge, `t` can be far outside [0, 1] here.
+    let on_edge = (-epsilon..=T::ONE + epsilon).contains(&u);
+    (on_edge && options.cut_mode.contains(t, epsilon)).then(|| edge.point_at(u))
+}
+
+/// Parts of the cut line that run through the inside of the polygon,
+/// from where the line enters to where it exits. A convex polygon has at
+/// most one chord, a concave one can have several. Parts running along
+/// the boundary are not chords, and a line only touching a vertex from
+/// the inside doesn't end a chord there.
+pub fn cut_chords<T: Scalar>(polygon_points: &[Point<T>], line: &Line<T>) -> Vec<Line<T>> {
+    cut_chords_with_options(polygon_points, line, &SliceOptions::default())
+}
+
+/// Same as `cut_chords` but with custom options.
+pub fn cut_chords_with_options<T: Scalar>(
+    polygon_points: &[Point<T>],
+    line: &Line<T>,
+    options: &SliceOptions<T>,
+) -> Vec<Line<T>> {
+    let intersections = find_intersections_with_options(polygon_points, line, options);
+    let inside = |p: &Point<T>| {
+        point_in_polygon(polygon_points, p)
+            && !edges(polygon_points).any(|edge| point_on_edge(p, &edge, options.epsilon))
+    };
+
+    let mut chords: Vec<Line<T>> = Vec::new();
+    for pair in intersections.windows(2) {
+        let span = Line::new(pair[0], pair[1]);
+        if !inside(&span.midpoint()) {
+            continue;
+        }
+        match chords.last_mut() {
+            // Carry on through a vertex that only touches the line.
+            Some(chord) if chord.p2 == span.p1 => chord.p2 = span.p2,
+            _ => chords.push(span),
+        }
+    }
+    chords
+}
+
+/// Total length of the cut line inside the polygon, see `cut_chords`.
+pub fn total_cut_length<T: Scalar>(polygon_points: &[Point<T>], line: &Line<T>) -> T {
+    cut_chords(polygon_points, line)
+        .iter()
+        .map(|chord| chord.length())
+        .sum()
+}
+
+/// Number of polygon edges the cut line meets, before duplicates are removed,
+/// so a cut through a vertex counts both edges meeting there.
+/// A full cut across a convex polygon crosses 2 edges, a concave one can cross more.
+/// Edges running along the line aren't counted, and neither is anything
+/// when a coordinate is NaN or infinite.
+pub fn edges_crossed<T: Scalar>(polygon_points: &[Point<T>], line: &Line<T>) -> usize {
+    edges_crossed_with_options(polygon_points, line, &SliceOptions::default())
+}
+
+/// Same as `edges_crossed` but with custom options.
+pub fn edges_crossed_with_options<T: Scalar>(
+    polygon_points: &[Point<T>],
+    line: &Line<T>,
+    options: &SliceOptions<T>,
+) -> usize {
+    if validate_polygon(polygon_points).is_err() || validate_line(line).is_err() {
+        return 0;
+    }
+    edges(open_ring(polygon_points, options.epsilon))
+        .filter(|edge| cut_edge_intersection(line, edge, options).is_some())
+        .count()
+}
+
+/// Utility to decide which polygon a point belongs to
+/// after being split by a given line.
+pub fn point_line_side<T: Scalar>(line: &Line<T>, p: &Point<T>) -> T {
+    // Same as (p - p1) x (p2 - p1), but with a reliable sign.
+    orient2d(&line.p2, &line.p1, p)
+}
+
+/// Classifies a `point_line_side` value as one side (1), the other side (-1)
+/// or on the line (0) within +-epsilon.
+fn side_sign<T: Scalar>(side: T, epsilon: T) -> i8 {
+    if side > epsilon {
+        1
+    } else if side < -epsilon {
+        -1
+    } else {
+        0
+    }
+}
+
+/// First vertex of the run of on-line vertices ending at `index`.
+/// There has to be a vertex off the line.
+fn run_start(signs: &[i8], index: usize) -> usize {
+    let count = signs.len();
+    let mut start = index;
+    while signs[(start + count - 1) % count] == 0 {
+        start = (start + count - 1) % count;
+    }
+    start
+}
+
+/// A place where the boundary passes from one side of the line to the other.
+/// `from_side` is the `side_sign` of the boundary just before it, 1 or -1.
+#[derive(Debug, Clone, Copy)]
+enum BoundaryCrossing<T> {
+    /// Between the ends of the edge starting at vertex `edge`.
+    Edge {
+        edge: usize,
+        point: Point<T>,
+        from_side: i8,
+    },
+    /// Through the on-line vertex `vertex`. When the boundary runs along the
+    /// line before crossing, `run_start` is the first vertex of that run,
+    /// otherwise it is `vertex` itself.
+    Vertex {
+        vertex: usize,
+        run_start: usize,
+        from_side: i8,
+    },
+    /// Not a crossing of the boundary, but of the line: the boundary only
+    /// touches the line along `run_start..=vertex`, as at the tip of a notch,
+    /// with the inside carrying on past both ends. The line leaves the inside
+    /// at one end and comes back in at the other, at `run_start` when
+    /// `enters_at_start`, walking along the line from `p1` towards `p2`.
+    Touch {
+        vertex: usize,
+        run_start: usize,
+        enters_at_start: bool,
+    },
+}
+
+/// Every place the boundary crosses the line, in boundary order, along with
+/// the `side_sign` of each vertex. Vertices touching the line from the
+/// outside are left out.
+fn boundary_crossings<T: Scalar>(
+    points: &[Point<T>],
+    line: &Line<T>,
+    epsilon: T,
+) -> (Vec<i8>, Vec<BoundaryCrossing<T>>) {
+    let point_count = points.len();
+    let sides: Vec<T> = points.iter().map(|p| point_line_side(line, p)).collect();
+    let signs: Vec<i8> = sides.iter().map(|side| side_sign(*side, epsilon)).collect();
+    let ccw = polygon_signed_area(points) > T::ZERO;
+
+    let mut crossings = Vec::new();
+    for (i, edge) in edges(points).enumerate() {
+        let next_index = (i + 1) % point_count;
+        if signs[i] * signs[next_index] < 0 {
+            let ratio = sides[i] / (sides[i] - sides[next_index]);
+            crossings.push(BoundaryCrossing::Edge {
+                edge: i,
+                point: edge.point_at(ratio),
+                from_side: signs[i],
+            });
+        } else if signs[i] == 0 && signs[next_index] != 0 {
+            // The last vertex of a run along the line, often a single vertex.
+            let run_start = run_start(&signs, i);
+            let previous = &points[(run_start + point_count - 1) % point_count];
+            let from_side = signs[(run_start + point_count - 1) % point_count];
+            if from_side == -signs[next_index] {
+                crossings.push(BoundaryCrossing::Vertex {
+                    vertex: i,
+                    run_start,
+                    from_side,
+                });
+                continue;
+            }
+
+            // Touching from the inside turns the other way than the polygon winds.
+            let turn = orient2d(
+                previous,
+                &points[run_start],
+                &points[(run_start + 1) % point_count],
+            );
+            if turn != T::ZERO && (turn > T::ZERO) != ccw {
+                // The inside next to the edge coming in is on its left for a
+                // counter-clockwise boundary, which is on the far side of the
+                // run from `p1` when the edge comes in from the left of the line.
+                crossings.push(BoundaryCrossing::Touch {
+                    vertex: i,
+                    run_start,
+                    enters_at_start: (from_side < 0) == ccw,
+                });
+            }
+        }
+    }
+    (signs, crossings)
+}
+
+/// Position of a point along the line, 0.0 at `p1` and 1.0 at `p2`.
+fn line_parameter<T: Scalar>(line: &Line<T>, p: &Point<T>) -> T {
+    let dx = line.p2.x - line.p1.x;
+    let dy = line.p2.y - line.p1.y;
+    ((p.x - line.p1.x) * dx + (p.y - line.p1.y) * dy) / (dx * dx + dy * dy)
+}
+
+/// A place where the polygon boundary crosses the cut line.
+struct Crossing<T> {
+    /// Index of the crossing point in the ring of vertices and intersections.
+    node: usize,
+    /// Position of the crossing along the cut line.
+    t: T,
+    /// When the boundary runs along the cut line before crossing it,
+    /// the node and position of the other end of that run.
+    run_start: Option<(usize, T)>,
+}
+
+impl<T: Scalar> Crossing<T> {
+    /// The node to cut from when the span continues towards `t`.
+    /// For a run along the cut line this is the end of the run facing `t`,
+    /// so the run stays part of the boundary instead of becoming a sliver.
+    fn end_facing(&self, t: T) -> (usize, T) {
+        match self.run_start {
+            Some((node, start_t)) if (start_t - t).abs() < (self.t - t).abs() => (node, start_t),
+            _ => (self.node, self.t),
+        }
+    }
+}
+
+/// Whether the cut line goes into or out of the polygon at a crossing,
+/// walking along the line from `p1` towards `p2`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CrossingKind {
+    Entry,
+    Exit,
+}
+
+/// Places where the boundary crosses the cut line, sorted along the line
+/// and tagged with whether the line enters or exits the polygon there.
+/// For a simple polygon cut by an infinite line they alternate, starting
+/// with an `Entry`. Where the boundary runs along the line before crossing it
+/// the end of that run facing the inside is used. Vertices touching the line
+/// from the outside are left out, while touching it from the inside, as at the
+/// tip of a notch, gives an `Exit` and an `Entry` at either end of the touch,
+/// as `split_polygon` cuts there. Non-finite coordinates give no crossings.
+pub fn classify_crossings<T: Scalar>(
+    polygon_points: &[Point<T>],
+    line: &Line<T>,
+) -> Vec<(Point<T>, CrossingKind)> {
+    classify_crossings_with_options(polygon_points, line, &SliceOptions::default())
+}
+
+/// Same as `classify_crossings` but with custom options.
+pub fn classify_crossings_with_options<T: Scalar>(
+    polygon_points: &[Point<T>],
+    line: &Line<T>,
+    options: &SliceOptions<T>,
+) -> Vec<(Point<T>, CrossingKind)> {
+    let epsilon = options.epsilon;
+    let polygon_points = &clean_polygon(polygon_points, epsilon)[..];
+    let point_count = polygon_points.len();
+    if point_count < 3
+        || line.length() < epsilon
+        || validate_polygon(polygon_points).is_err()
+        || validate_line(line).is_err()
+    {
+        return Vec::new();
+    }
+
+    // The inside is on the left of a counter-clockwise boundary, so crossing
+    // from the positive side (right of the line) to the negative side
+    // leaves the inside behind the crossing.
+    let ccw = polygon_signed_area(polygon_points) > T::ZERO;
+    let kind_from = |sign: i8| {
+        if (sign > 0) == ccw {
+            CrossingKind::Exit
+        } else {
+            CrossingKind::Entry
+        }
+    };
+
+    let at = |index: usize, kind| {
+        let point = polygon_points[index];
+        (line_parameter(line, &point), point, kind)
+    };
+    let (_, boundary) = boundary_crossings(polygon_points, line, epsilon);
+    let mut crossings: Vec<(T, Point<T>, CrossingKind)> = Vec::new();
+    for crossing in boundary {
+        match crossing {
+            BoundaryCrossing::Edge {
+                point, from_side, ..
+            } => crossings.push((line_parameter(line, &point), point, kind_from(from_side))),
+            BoundaryCrossing::Vertex {
+                vertex,
+                run_start,
+                from_side,
+            } => {
+                // The inside starts after the run for an entry and ends before it for an exit.
+                let kind = kind_from(from_side);
+                let (start, end) = (at(run_start, kind), at(vertex, kind));
+                let use_end = (end.0 > start.0) == (kind == CrossingKind::Entry);
+                crossings.push(if use_end { end } else { start });
+            }
+            BoundaryCrossing::Touch {
+                vertex,
+                run_start,
+                enters_at_start,
+            } => {
+                // The exit goes first, so it still sorts first at a single vertex.
+                let (entry, exit) = if enters_at_start {
+                    (run_start, vertex)
+                } else {
+                    (vertex, run_start)
+                };
+                crossings.push(at(exit, CrossingKind::Exit));
+                crossings.push(at(entry, CrossingKind::Entry));
+            }
+        }
+    }
+
+    crossings.retain(|(t, _, _)| options.cut_mode.contains(*t, epsilon));
+    crossings.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal));
+    crossings
+        .into_iter()
+        .map(|(_, point, kind)| (point, kind))
+        .collect()
+}
+
+/// Why `split_polygon` couldn't cut a polygon.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SliceError {
+    /// The polygon has fewer than three points.
+    NotEnoughVertices,
+    /// The cut line has no length, so it has no direction to cut along.
+    DegenerateLine,
+    /// The line misses the polygon or only touches its boundary.
+    NoIntersection,
+    /// The boundary crosses the line an odd number of times,
+    /// which can't happen for a simple polygon.
+    NonSimplePolygon,
+    /// A coordinate of the polygon or the line is NaN or infinite.
+    NonFinite,
+}
+
+impl fmt::Display for SliceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let message = match self {
+            SliceError::NotEnoughVertices => "polygon has fewer than three points",
+            SliceError::DegenerateLine => "cut line has zero length",
+            SliceError::NoIntersection => "cut line does not cross the polygon",
+            SliceError::NonSimplePolygon => "polygon is not simple",
+            SliceError::NonFinite => "coordinate is not a finite number",
+        };
+        write!(f, "{}", message)
+    }
+}
+
+impl std::error::Error for SliceError {}
+
+fn point_is_finite<T: Scalar>(p: &Point<T>) -> bool {
+    p.x.is_finite() && p.y.is_finite()
+}
+
+/// Checks that every coordinate of the polygon is finite.
+/// A NaN compares as neither smaller nor larger than anything, so it would
+/// otherwise turn into wrong crossings and NaN areas instead of an error.
+pub fn validate_polygon<T: Scalar>(points: &[Point<T>]) -> Result<(), SliceError> {
+    if points.iter().all(point_is_finite) {
+        Ok(())
+    } else {
+        Err(SliceError::NonFinite)
+    }
+}
+
+/// Checks that both end points of the line are finite, see `validate_polygon`.
+pub fn validate_line<T: Scalar>(line: &Line<T>) -> Result<(), SliceError> {
+    if point_is_finite(&line.p1) && point_is_finite(&line.p2) {
+        Ok(())
+    } else {
+        Err(SliceError::NonFinite)
+    }
+}
+
+/// `validate_polygon` and `validate_line` for every line at once.
+fn validate_input<T: Scalar>(points: &[Point<T>], lines: &[Line<T>]) -> Result<(), SliceError> {
+    validate_polygon(points)?;
+    lines.iter().try_for_each(validate_line)
+}
+
+/// Splits a polygon and returns vector of polygons.
+/// Returns a `SliceError` saying why if no valid cut can be made.
+///
+/// The boundary crossings are sorted along the cut line and paired up as
+/// (enter, exit) spans, so a concave polygon can be split into more than
+/// two pieces by a single line. Spans that do not lie entirely on the
+/// line segment are not cut, unless `SliceOptions::cut_mode` extends it.
+///
+/// A vertex lying on the line is used as the crossing point itself, so it
+/// ends up exactly once in each fragment that shares it. Where the boundary
+/// only touches the line from the inside, at a reflex vertex or along the
+/// floor of a notch, the span stops there, so the pieces on either side come
+/// out separately. Touching from the outside cuts nothing. Likewise, an edge
+/// lying along the line stays part of the boundary and never turns into a
+/// zero area sliver, so a cut along the outside of the polygon leaves it
+/// unchanged.
+///
+/// The polygon has to be simple, which can be checked with `is_simple`,
+/// and then so is every fragment.
+pub fn split_polygon<T: Scalar>(
+    polygon_points: &[Point<T>],
+    line: &Line<T>,
+) -> Result<Vec<Vec<Point<T>>>, SliceError> {
+    split_polygon_with_options(polygon_points, line, &SliceOptions::default())
+}
+
+/// Same as `split_polygon` but with custom options.
+pub fn split_polygon_with_options<T: Scalar>(
+    polygon_points: &[Point<T>],
+    line: &Line<T>,
+    options: &SliceOptions<T>,
+) -> Result<Vec<Vec<Point<T>>>, SliceError> {
+    let (ring, spans) = cut_spans(polygon_points, line, options)?;
+    let mut partner: Vec<Option<usize>> = vec![None; ring.len()];
+    for (enter, exit) in spans {
+        partner[enter] = Some(exit);
+        partner[exit] = Some(enter);
+    }
+
+    // Trace each piece by following the boundary and jumping across
+    // a span whenever one of its end points is reached.
+    let mut visited = vec![false; ring.len()];
+    let mut polygons = Vec::new();
+    for start in 0..ring.len() {
+        if visited[start] || partner[start].is_some() {
+            continue;
+        }
+
+        let mut polygon = Vec::new();
+        let mut index = start;
+        loop {
+            visited[index] = true;
+            polygon.push(ring[index]);
+            if let Some(other) = partner[index] {
+                polygon.push(ring[other]);
+                index = other;
+            }
+
+            index = (index + 1) % ring.len();
+            if index == start {
+                break;
+            }
+        }
+
+        // Both nodes of a twinned vertex end up in the piece passing it.
+        polygon.dedup();
+        if polygon.len() > 1 && polygon[0] == polygon[polygon.len() - 1] {
+            polygon.pop();
+        }
+        polygons.push(polygon);
+    }
+
+    Ok(polygons)
+}
+
+/// Nodes at both ends of a cut across the polygon, entry first.
+type Span = (usize, usize);
+
+/// The first half of `split_polygon`: the boundary with a node inserted
+/// wherever an edge crosses the line, and the pairs of nodes the cut joins.
+/// Each span splits one piece in two, so there are one more pieces than spans.
+fn cut_spans<T: Scalar>(
+    polygon_points: &[Point<T>],
+    line: &Line<T>,
+    options: &SliceOptions<T>,
+) -> Result<(Vec<Point<T>>, Vec<Span>), SliceError> {
+    validate_polygon(polygon_points)?;
+    validate_line(line)?;
+    let epsilon = options.epsilon;
+    // Repeated points would make zero length edges with no side to be on.
+    let polygon_points = &clean_polygon(polygon_points, epsilon)[..];
+    let point_count = polygon_points.len();
+    if point_count < 3 {
+        return Err(SliceError::NotEnoughVertices);
+    }
+    if line.length() < epsilon {
+        return Err(SliceError::DegenerateLine);
+    }
+
+    let (signs, boundary) = boundary_crossings(polygon_points, line, epsilon);
+
+    // Nothing to cut unless there are points on both sides of the line.
+    if !signs.contains(&1) || !signs.contains(&-1) {
+        return Err(SliceError::NoIntersection);
+    }
+
+    // Walk the boundary and insert a point wherever an edge crosses the line.
+    // A single vertex touched from the inside gets a second node right after
+    // its own, so that the spans on either side of it each end at their own node.
+    let mut edge_points = vec![None; point_count];
+    let mut twinned = vec![false; point_count];
+    for crossing in &boundary {
+        match *crossing {
+            BoundaryCrossing::Edge { edge, point, .. } => edge_points[edge] = Some(point),
+            BoundaryCrossing::Touch {
+                vertex, run_start, ..
+            } => twinned[vertex] = vertex == run_start,
+            BoundaryCrossing::Vertex { .. } => {}
+        }
+    }
+    let mut ring = Vec::new();
+    let mut crossings = Vec::new();
+    let mut vertex_nodes = Vec::with_capacity(point_count);
+    for ((vertex, edge_point), twinned) in polygon_points.iter().zip(edge_points).zip(twinned) {
+        ring.push(*vertex);
+        vertex_nodes.push(ring.len() - 1);
+        if twinned {
+            ring.push(*vertex);
+        }
+        if let Some(intersection) = edge_point {
+            ring.push(intersection);
+            crossings.push(Crossing {
+                node: ring.len() - 1,
+                t: line_parameter(line, &intersection),
+                run_start: None,
+            });
+        }
+    }
+
+    // Vertices on the line are resolved once every vertex has its node,
+    // since a run along the line can wrap around the start of the polygon.
+    let node_crossing = |node: usize, vertex: usize| Crossing {
+        node,
+        t: line_parameter(line, &polygon_points[vertex]),
+        run_start: None,
+    };
+    for crossing in boundary {
+        match crossing {
+            BoundaryCrossing::Edge { .. } => {}
+            BoundaryCrossing::Vertex {
+                vertex, run_start, ..
+            } => crossings.push(Crossing {
+                run_start: (run_start != vertex).then(|| {
+                    (
+                        vertex_nodes[run_start],
+                        line_parameter(line, &polygon_points[run_start]),
+                    )
+                }),
+                ..node_crossing(vertex_nodes[vertex], vertex)
+            }),
+            BoundaryCrossing::Touch {
+                vertex,
+                run_start,
+                enters_at_start,
+            } => {
+                // A single vertex uses its twin as the node at the end of the run.
+                let first = vertex_nodes[run_start];
+                let last = if vertex == run_start {
+                    first + 1
+                } else {
+                    vertex_nodes[vertex]
+                };
+                let (entry, exit) = if enters_at_start {
+                    (node_crossing(first, run_start), node_crossing(last, vertex))
+                } else {
+                    (node_crossing(last, vertex), node_crossing(first, run_start))
+                };
+                // The exit goes first, so it still sorts first at a single vertex.
+                crossings.push(exit);
+                crossings.push(entry);
+            }
+        }
+    }
+
+    if crossings.len() % 2 != 0 {
+        return Err(SliceError::NonSimplePolygon);
+    }
+
+    // Consecutive crossings along the line enclose a span inside the polygon.
+    crossings.sort_by(|a, b| a.t.partial_cmp(&b.t).unwrap_or(std::cmp::Ordering::Equal));
+    let on_cut = |t: T| options.cut_mode.contains(t, epsilon);
+    let mut spans = Vec::new();
+    for span in crossings.chunks(2) {
+        let (enter, enter_t) = span[0].end_facing(span[1].t);
+        let (exit, exit_t) = span[1].end_facing(enter_t);
+        if on_cut(enter_t) && on_cut(exit_t) {
+            spans.push((enter, exit));
+        }
+    }
+
+    if spans.is_empty() {
+        return Err(SliceError::NoIntersection);
+    }
+
+    Ok((ring, spans))
+}
+
+/// Splits a polygon in two along an open polyline, e.g. an L shaped cut.
+/// The chain has to start outside and cross the boundary exactly twice, once
+/// going in and once coming out, and must not cross itself on the way.
+/// Returns None otherwise, including when a segment of the chain runs along an edge.
+/// Both pieces keep the winding of the polygon.
+pub fn split_polygon_by_chain<T: Scalar>(
+    polygon_points: &[Point<T>],
+    chain: &[Point<T>],
+) -> Option<Vec<Vec<Point<T>>>> {
+    split_polygon_by_chain_with_options(polygon_points, chain, &SliceOptions::default())
+}
+
+/// Same as `split_polygon_by_chain` but with custom options.
+/// The ends of the chain are never extended, so `options.cut_mode` is not used.
+pub fn split_polygon_by_chain_with_options<T: Scalar>(
+    polygon_points: &[Point<T>],
+    chain: &[Point<T>],
+    options: &SliceOptions<T>,
+) -> Option<Vec<Vec<Point<T>>>> {
+    let epsilon = options.epsilon;
+    let polygon_points = &clean_polygon(polygon_points, epsilon)[..];
+    if polygon_points.len() < 3 || chain.len() < 2 {
+        return None;
+    }
+
+    // Every crossing as (chain segment, position along it, edge, position along the edge).
+    let mut crossings = Vec::new();
+    for (segment_index, segment) in chain.windows(2).enumerate() {
+        let segment = Line::new(segment[0], segment[1]);
+        for (edge_index, edge) in edges(polygon_points).enumerate() {
+            match segment_intersection_with_options(&segment, &edge, options) {
+                Intersection::None => {}
+                Intersection::Point(p) => crossings.push((
+                    segment_index,
+                    line_parameter(&segment, &p),
+                    edge_index,
+                    line_parameter(&edge, &p),
+                    p,
+                )),
+                Intersection::Collinear(_, _) => return None,
+            }
+        }
+    }
+    crossings.sort_by(|a, b| {
+        a.0.cmp(&b.0)
+            .then(a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal))
+    });
+    // A crossing through a vertex is found on both edges meeting there.
+    crossings.dedup_by(|a, b| points_within(&a.4, &b.4, epsilon));
+
+    let [enter, exit] = crossings[..] else {
+        return None;
+    };
+    let (enter_segment, _, enter_edge, enter_u, enter) = enter;
+    let (exit_segment, _, exit_edge, exit_u, exit) = exit;
+
+    // The part of the chain inside the polygon, from where it enters to where it exits.
+    let mut inside = vec![enter];
+    inside.extend_from_slice(&chain[enter_segment + 1..=exit_segment]);
+    inside.push(exit);
+
+    // Going out and back in, e.g. from a start inside the polygon, puts the
+    // run between the two crossings outside.
+    if !point_in_polygon(polygon_points, &Line::new(inside[0], inside[1]).midpoint()) {
+        return None;
+    }
+    let runs: Vec<Line<T>> = inside.windows(2).map(|w| Line::new(w[0], w[1])).collect();
+    for (i, a) in runs.iter().enumerate() {
+        for b in runs.iter().skip(i + 2) {
+            if line_segment_intersection_with_options(a, b, options).is_some() {
+                return None;
+            }
+        }
+    }
+
+    let mut first = boundary_between(polygon_points, (enter_edge, enter_u), (exit_edge, exit_u));
+    first.extend(inside.iter().rev());
+    let mut second = boundary_between(polygon_points, (exit_edge, exit_u), (enter_edge, enter_u));
+    second.extend(inside);
+
+    let pieces: Vec<Vec<Point<T>>> = [first, second]
+        .iter()
+        .map(|piece| clean_polygon(piece, epsilon))
+        .collect();
+    pieces
+        .iter()
+        .all(|piece| polygon_area(piece) > epsilon)
+        .then_some(pieces)
+}
+
+/// Vertices passed walking the boundary forwards from a point on one edge
+/// to a point on another, each given as (edge index, position along the edge).
+/// The end points themselves are not included.
+fn boundary_between<T: Scalar>(
+    points: &[Point<T>],
+    from: (usize, T),
+    to: (usize, T),
+) -> Vec<Point<T>> {
+    let count = points.len();
+    if from.0 == to.0 && from.1 <= to.1 {
+        return Vec::new();
+    }
+
+    let mut walked = Vec::new();
+    let mut edge = from.0;
+    loop {
+        edge = (edge + 1) % count;
+        walked.push(points[edge]);
+        if edge == to.0 {
+            return walked;
+        }
+    }
+}
+
+/// Smallest circle containing every point, as `(center, radius)`,
+/// using Welzl's algorithm in its iterative form. Returns None for empty input.
+/// Points are taken in the given order rather than shuffled, so the
+/// expected linear time only holds for input that isn't ordered adversarially.
+pub fn min_enclosing_circle<T: Scalar>(points: &[Point<T>]) -> Option<(Point<T>, T)> {
+    let half = T::from_f64(0.5);
+    let outside =
+        |circle: &(Point<T>, T), p: &Point<T>| circle.0.distance(p) > circle.1 + T::EPSILON;
+    let diameter = |a: &Point<T>, b: &Point<T>| ((*a + *b) * half, a.distance(b) * half);
+
+    let mut circle = (*points.first()?, T::ZERO);
+    for i in 1..points.len() {
+        if !outside(&circle, &points[i]) {
+            continue;
+        }
+        // The point must lie on the boundary of the circle for the first i + 1 points.
+        circle = (points[i], T::ZERO);
+        for j in 0..i {
+            if !outside(&circle, &points[j]) {
+                continue;
+            }
+            circle = diameter(&points[i], &points[j]);
+            for k in 0..j {
+                if outside(&circle, &points[k]) {
+                    circle =
+                        circumcircle(&points[i], &points[j], &points[k]).unwrap_or_else(|| {
+                            // Collinear, so the two points furthest apart span the circle.
+                            let candidates = [
+                                diameter(&points[i], &points[j]),
+                                diameter(&points[i], &points[k]),
+                                diameter(&points[j], &points[k]),
+                            ];
+                            candidates
+                                .into_iter()
+                                .reduce(|a, b| if b.1 > a.1 { b } else { a })
+                                .unwrap()
+                        });
+                }
+            }
+        }
+    }
+    Some(circle)
+}
+
+/// Circle through three points, None if they are collinear.
+fn circumcircle<T: Scalar>(a: &Point<T>, b: &Point<T>, c: &Point<T>) -> Option<(Point<T>, T)> {
+    let ab = *b - *a;
+    let ac = *c - *a;
+    let d = ab.cross(&ac) * T::from_f64(2.0);
+    if d.abs() < T::EPSILON {
+        return None;
+    }
+
+    let ab_squared = ab.dot(&ab);
+    let ac_squared = ac.dot(&ac);
+    let offset = Point::new(
+        (ac.y * ab_squared - ab.y * ac_squared) / d,
+        (ab.x * ac_squared - ac.x * ab_squared) / d,
+    );
+    Some((*a + offset, offset.x.hypot(offset.y)))
+}
+
+/// Largest distance between any two of the points.
+/// Uses rotating calipers on the convex hull, since the farthest pair
+/// are always hull vertices. Returns zero for fewer than two points.
+pub fn polygon_diameter<T: Scalar>(points: &[Point<T>]) -> T {
+    let hull = convex_hull(points);
+    let n = hull.len();
+    if n < 3 {
+        return match hull.as_slice() {
+            [a, b] => a.distance(b),
+            _ => T::ZERO,
+        };
+    }
+
+    let mut diameter = T::ZERO;
+    let mut j = 1;
+    for i in 0..n {
+        let (a, b) = (&hull[i], &hull[(i + 1) % n]);
+        // Advance the opposite vertex while it gets further from edge ab.
+        while orient2d(a, b, &hull[(j + 1) % n]) > orient2d(a, b, &hull[j]) {
+            j = (j + 1) % n;
+        }
+        diameter = diameter.max(a.distance(&hull[j])).max(b.distance(&hull[j]));
+    }
+    diameter
+}
+
+/// Axis aligned bounding box of the points as `(min, max)` corners.
+/// Returns None for empty input.
+pub fn bounding_box<T: Scalar>(points: &[Point<T>]) -> Option<(Point<T>, Point<T>)> {
+    let first = *points.first()?;
+    let (mut min, mut max) = (first, first);
+    for point in &points[1..] {
+        min.x = min.x.min(point.x);
+        min.y = min.y.min(point.y);
+        max.x = max.x.max(point.x);
+        max.y = max.y.max(point.y);
+    }
+
+    Some((min, max))
+}
+
+/// Mirrors every vertex across the infinite line through `line.p1` and `line.p2`.
+/// The vertices keep their order, so the winding is reversed, e.g. a
+/// counter-clockwise polygon comes back clockwise. Use `ensure_ccw`
+/// afterwards if the orientation matters. A zero length line has no
+/// direction to mirror across and returns the points unchanged.
+pub fn reflect_polygon<T: Scalar>(points: &[Point<T>], line: &Line<T>) -> Vec<Point<T>> {
+    if line.p1 == line.p2 {
+        return points.to_vec();
+    }
+
+    points
+        .iter()
+        .map(|p| {
+            let foot = line.point_at(line_parameter(line, p));
+            foot * T::from_f64(2.0) - *p
+        })
+        .collect()
+}
+
+/// Areas of the polygon on either side of the infinite line through `line`,
+/// as `(positive, negative)` following the sign of `point_line_side`.
+/// The polygon is clipped against each half-plane (Sutherland-Hodgman)
+/// without building the fragments, so any number of crossings works.
+pub fn halfplane_areas<T: Scalar>(points: &[Point<T>], line: &Line<T>) -> (T, T) {
+    let sides: Vec<T> = points.iter().map(|p| point_line_side(line, p)).collect();
+    (
+        polygon_area(&clip_to_halfplane(points, &sides, T::ONE)),
+        polygon_area(&clip_to_halfplane(points, &sides, -T::ONE)),
+    )
+}
+
+/// Line through `pivot` that splits the polygon into two halves of equal area,
+/// found by binary searching the angle of the cut with `halfplane_areas`.
+/// The line reaches across the whole polygon, so it can be passed straight
+/// to `split_polygon`. Returns None if the pivot is outside the polygon or
+/// the search doesn't settle within EPSILON (relative to the area).
+pub fn area_bisector_through<T: Scalar>(points: &[Point<T>], pivot: &Point<T>) -> Option<Line<T>> {
+    if !point_in_polygon(points, pivot) {
+        return None;
+    }
+    let (min, max) = bounding_box(points)?;
+    let reach = min.distance(&max);
+    if reach == T::ZERO {
+        return None;
+    }
+
+    let cut = |angle: f64| {
+        let direction = Point::new(T::from_f64(angle.cos()), T::from_f64(angle.sin())) * reach;
+        Line::new(*pivot - direction, *pivot + direction)
+    };
+    let imbalance = |angle: f64| {
+        let (positive, negative) = halfplane_areas(points, &cut(angle));
+        positive - negative
+    };
+    let tolerance = T::EPSILON * polygon_area(points).max(T::ONE);
+
+    // Turning the line half a revolution swaps its sides, so the imbalance
+    // changes sign somewhere in between.
+    let mut low = 0.0;
+    let mut high = std::f64::consts::PI;
+    let low_positive = imbalance(low) > T::ZERO;
+    for _ in 0..100 {
+        let angle = (low + high) / 2.0;
+        let difference = imbalance(angle);
+        if difference.abs() <= tolerance {
+            return Some(cut(angle));
+        }
+        if (difference > T::ZERO) == low_positive {
+            low = angle;
+        } else {
+            high = angle;
+        }
+    }
+
+    (imbalance(low).abs() <= tolerance).then(|| cut(low))
+}
+
+/// Part of `subject` inside `clip`, using Sutherland-Hodgman clipping.
+/// `clip` must be convex, see `is_convex`, but may wind either way.
+/// `subject` may be concave, although then a result that falls apart
+/// into several pieces comes back joined by zero area edges.
+/// Returns an empty vector when the two don't overlap.
+pub fn clip_polygon<T: Scalar>(subject: &[Point<T>], clip: &[Point<T>]) -> Vec<Point<T>> {
+    // The inside is left of each clip edge for counter-clockwise winding,
+    // which is the negative side of `point_line_side`.
+    let inside = if is_ccw(clip) { -T::ONE } else { T::ONE };
+
+    let mut clipped = subject.to_vec();
+    for edge in edges(clip) {
+        if clipped.is_empty() {
+            break;
+        }
+        let sides: Vec<T> = clipped.iter().map(|p| point_line_side(&edge, p)).collect();
+        clipped = clip_to_halfplane(&clipped, &sides, inside);
+    }
+
+    if polygon_area(&clipped) == T::ZERO {
+        clipped.clear();
+    }
+    clipped
+}
+
+/// Area of the part of `a` inside `b`, see `clip_polygon`.
+/// `b` must be convex, `a` may be concave.
+pub fn intersection_area<T: Scalar>(a: &[Point<T>], b: &[Point<T>]) -> T {
+    polygon_area(&clip_polygon(a, b))
+}
+
+/// Part of the polygon inside the axis aligned rectangle from `min` to `max`,
+/// e.g. a viewport. Same as `clip_polygon` with the rectangle as `clip`, but
+/// measures the sides straight from the coordinates instead of with cross products.
+/// Returns an empty vector when the two don't overlap.
+pub fn clip_to_rect<T: Scalar>(points: &[Point<T>], min: Point<T>, max: Point<T>) -> Vec<Point<T>> {
+    // Coordinate (x or y), bound and which side of it is inside,
+    // for the left, right, bottom and top of the rectangle.
+    let bounds = [
+        (true, min.x, T::ONE),
+        (true, max.x, -T::ONE),
+        (false, min.y, T::ONE),
+        (false, max.y, -T::ONE),
+    ];
+
+    let mut clipped = points.to_vec();
+    for (along_x, bound, inside) in bounds {
+        if clipped.is_empty() {
+            break;
+        }
+        let sides: Vec<T> = clipped
+            .iter()
+            .map(|p| if along_x { p.x } else { p.y } - bound)
+            .collect();
+        clipped = clip_to_halfplane(&clipped, &sides, inside);
+    }
+
+    if polygon_area(&clipped) == T::ZERO {
+        clipped.clear();
+    }
+    clipped
+}
+
+/// Union of two convex polygons as one counter-clockwise polygon,
+/// which isn't convex itself in general. Polygons that only touch are joined
+/// when they share part of an edge. Returns None when the union isn't a
+/// single polygon, i.e. they are apart or only touch in a corner.
+pub fn union_convex<T: Scalar>(a: &[Point<T>], b: &[Point<T>]) -> Option<Vec<Point<T>>> {
+    let epsilon = T::EPSILON;
+    if a.len() < 3 || b.len() < 3 {
+        return None;
+    }
+
+    // Any point inside both sees all of both, so the union is star shaped
+    // around it and its outline comes out in order of angle around that point.
+    let Some(center) = polygon_centroid(&clip_polygon(a, b)) else {
+        return merge_fragments(
+            &with_vertices_on_edges(a, b, epsilon),
+            &with_vertices_on_edges(b, a, epsilon),
+            epsilon,
+        );
+    };
+
+    let strictly_inside = |points: &[Point<T>], p: &Point<T>| {
+        point_in_polygon(points, p) && !edges(points).any(|edge| point_on_edge(p, &edge, epsilon))
+    };
+    let mut outline: Vec<Point<T>> = a
+        .iter()
+        .filter(|p| !strictly_inside(b, p))
+        .chain(b.iter().filter(|p| !strictly_inside(a, p)))
+        .copied()
+        .collect();
+    for edge_a in edges(a) {
+        for edge_b in edges(b) {
+            if let Intersection::Point(p) = segment_intersection(&edge_a, &edge_b) {
+                outline.push(p);
+            }
+        }
+    }
+
+    let angle = |p: &Point<T>| (p.y - center.y).atan2(p.x - center.x);
+    outline.sort_by(|p, q| {
+        angle(p)
+            .partial_cmp(&angle(q))
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+    let outline = clean_polygon(&outline, epsilon);
+    Some(remove_collinear_vertices(&outline, epsilon))
+}
+
+/// The polygon with every vertex of `other` that lies on one of its edges
+/// added to that edge, so shared parts of edges start and end at the same points.
+fn with_vertices_on_edges<T: Scalar>(
+    points: &[Point<T>],
+    other: &[Point<T>],
+    epsilon: T,
+) -> Vec<Point<T>> {
+    let mut result = Vec::new();
+    for edge in edges(points) {
+        result.push(edge.p1);
+        let mut on_edge: Vec<Point<T>> = other
+            .iter()
+            .filter(|p| {
+                point_on_edge(p, &edge, epsilon)
+                    && !points_within(p, &edge.p1, epsilon)
+                    && !points_within(p, &edge.p2, epsilon)
+            })
+            .copied()
+            .collect();
+        on_edge.sort_by(|p, q| {
+            line_parameter(&edge, p)
+                .partial_cmp(&line_parameter(&edge, q))
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+        result.extend(on_edge);
+    }
+    result
+}
+
+/// Keeps the part of the polygon where `side * sign` is not negative.
+fn clip_to_halfplane<T: Scalar>(points: &[Point<T>], sides: &[T], sign: T) -> Vec<Point<T>> {
+    let mut clipped = Vec::new();
+    for i in 0..points.len() {
+        let next_index = (i + 1) % points.len();
+        let current_side = sides[i] * sign;
+        let next_side = sides[next_index] * sign;
+
+        if current_side >= T::ZERO {
+            clipped.push(points[i]);
+        }
+        if (current_side < T::ZERO) != (next_side < T::ZERO) {
+            let ratio = current_side / (current_side - next_side);
+            clipped.push(Line::new(points[i], points[next_index]).point_at(ratio));
+        }
+    }
+    clipped
+}
+
+/// Checks that no two non-adjacent edges of the polygon touch or cross,
+/// e.g. a "bowtie" polygon is not simple.
+/// Area and slicing results are meaningless for polygons that aren't simple.
+pub fn is_simple<T: Scalar>(points: &[Point<T>]) -> bool {
+    let point_count = points.len();
+    let edge = |index: usize| Line::new(points[index], points[(index + 1) % point_count]);
+
+    for first in 0..point_count {
+        for second in first + 1..point_count {
+            // Adjacent edges always share a vertex.
+            let adjacent = second == first + 1 || (first == 0 && second == point_count - 1);
+            if !adjacent && line_segment_intersection(&edge(first), &edge(second)).is_some() {
+                return false;
+            }
+        }
+    }
+
+    true
+}
+
+/// Checks that the polygon turns the same way at every vertex,
+/// in either winding. Collinear vertices, within EPSILON, are allowed.
+/// Assumes a simple polygon, e.g. a pentagram turns the same way
+/// everywhere but is not convex.
+pub fn is_convex<T: Scalar>(points: &[Point<T>]) -> bool {
+    let point_count = points.len();
+    if point_count < 3 {
+        return false;
+    }
+
+    let mut sign = 0;
+    for point_index in 0..point_count {
+        let prev = &points[(point_index + point_count - 1) % point_count];
+        let current = &points[point_index];
+        let next = &points[(point_index + 1) % point_count];
+
+        let turn = side_sign(orient2d(prev, current, next), T::EPSILON);
+        if turn == 0 {
+            continue;
+        }
+        if sign == 0 {
+            sign = turn;
+        } else if turn != sign {
+            return false;
+        }
+    }
+
+    // All collinear is a line, not a polygon.
+    sign != 0
+}
+
+/// Interior angle at every vertex in radians, in the order of the points.
+/// The inside is taken from the winding, so either winding gives the same
+/// angles. Angles above pi are at reflex vertices, see `reflex_vertices`.
+pub fn interior_angles<T: Scalar>(points: &[Point<T>]) -> Vec<T> {
+    let point_count = points.len();
+    if point_count < 3 {
+        return Vec::new();
+    }
+
+    let pi = T::from_f64(std::f64::consts::PI);
+    let winding = if is_ccw(points) { T::ONE } else { -T::ONE };
+    (0..point_count)
+        .map(|i| {
+            let current = points[i];
+            let to_prev = points[(i + point_count - 1) % point_count] - current;
+            let to_next = points[(i + 1) % point_count] - current;
+            // Turning from the next vertex to the previous one sweeps the inside.
+            let angle = (to_next.cross(&to_prev) * winding).atan2(to_next.dot(&to_prev));
+            if angle < T::ZERO {
+                angle + pi + pi
+            } else {
+                angle
+            }
+        })
+        .collect()
+}
+
+/// Indices of the vertices where the interior angle is more than pi,
+/// i.e. where the boundary turns the other way than the winding.
+/// Uses `orient2d`, so a vertex on a straight edge is never reflex.
+pub fn reflex_vertices<T: Scalar>(points: &[Point<T>]) -> Vec<usize> {
+    let point_count = points.len();
+    if point_count < 3 {
+        return Vec::new();
+    }
+
+    let winding = if is_ccw(points) { T::ONE } else { -T::ONE };
+    (0..point_count)
+        .filter(|&i| {
+            let prev = &points[(i + point_count - 1) % point_count];
+            let next = &points[(i + 1) % point_count];
+            orient2d(prev, &points[i], next) * winding < T::ZERO
+        })
+        .collect()
+}
+
+/// Removes consecutive duplicate points, within `epsilon`, including
+/// an explicit closing point equal to the first one.
+/// `split_polygon` does this itself, so its input may contain duplicates.
+pub fn clean_polygon<T: Scalar>(points: &[Point<T>], epsilon: T) -> Vec<Point<T>> {
+    let mut cleaned = points.to_vec();
+    cleaned.dedup_by(|a, b| points_within(a, b, epsilon));
+    while cleaned.len() > 1 && points_within(&cleaned[0], &cleaned[cleaned.len() - 1], epsilon) {
+        cleaned.pop();
+    }
+    cleaned
+}
+
+/// Whether two rings outline the same polygon, with each vertex within
+/// `epsilon`, whichever vertex they start at and whichever way they wind.
+/// Handy for comparing fragments, whose starting point depends on the cut.
+pub fn polygon_eq<T: Scalar>(a: &[Point<T>], b: &[Point<T>], epsilon: T) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    if a.is_empty() {
+        return true;
+    }
+
+    let n = a.len();
+    (0..n).any(|start| {
+        let forward = (0..n).all(|i| points_within(&a[i], &b[(start + i) % n], epsilon));
+        let backward = (0..n).all(|i| points_within(&a[i], &b[(start + n - i) % n], epsilon));
+        forward || backward
+    })
+}
+
+/// Rounds every coordinate to the nearest multiple of `cell`, so input
+/// with float noise (e.g. `0.9999999999`) slices the same way every time.
+/// Neighbouring vertices can end up on the same grid point, pass the result
+/// through `clean_polygon` to drop those.
+pub fn snap_to_grid<T: Scalar>(points: &[Point<T>], cell: T) -> Vec<Point<T>> {
+    points
+        .iter()
+        .map(|p| Point::new((p.x / cell).round() * cell, (p.y / cell).round() * cell))
+        .collect()
+}
+
+/// Drops every vertex that lies on the straight line between its neighbours,
+/// within `epsilon` distance. Winding and shape are kept, only the
+/// redundant vertices along straight edges go away.
+pub fn remove_collinear_vertices<T: Scalar>(points: &[Point<T>], epsilon: T) -> Vec<Point<T>> {
+    let mut simplified = points.to_vec();
+    let collinear = |a: &Point<T>, b: &Point<T>, c: &Point<T>| {
+        orient2d(a, b, c).abs() <= epsilon * a.distance(c)
+    };
+
+    // Removing a vertex can make its neighbour collinear in turn,
+    // so keep going until a full pass changes nothing.
+    let mut changed = true;
+    while changed {
+        changed = false;
+        let mut index = 0;
+        while index < simplified.len() && simplified.len() > 3 {
+            let count = simplified.len();
+            let prev = &simplified[(index + count - 1) % count];
+            let next = &simplified[(index + 1) % count];
+            if collinear(prev, &simplified[index], next) {
+                simplified.remove(index);
+                changed = true;
+            } else {
+                index += 1;
+            }
+        }
+    }
+
+    simplified
+}
+
+/// Ramer-Douglas-Peucker simplification of the closed ring: drops vertices
+/// until every removed one is within `epsilon` of the simplified outline.
+/// The first vertex is always kept, and the vertex farthest from it splits
+/// the ring into the two chains that are simplified.
+/// Tolerances close to the size of the polygon's features can make the
+/// result self-intersect, check with `is_simple` if that matters.
+pub fn simplify_rdp<T: Scalar>(points: &[Point<T>], epsilon: T) -> Vec<Point<T>> {
+    let point_count = points.len();
+    if point_count < 3 {
+        return points.to_vec();
+    }
+
+    // Walk the ring as an open chain ending back at the first vertex.
+    let mut ring = points.to_vec();
+    ring.push(points[0]);
+    let farthest = (1..point_count)
+        .max_by(|a, b| {
+            let distance_a = points[0].distance_squared(&points[*a]);
+            let distance_b = points[0].distance_squared(&points[*b]);
+            distance_a
+                .partial_cmp(&distance_b)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        })
+        .unwrap_or(1);
+
+    let mut keep = vec![false; point_count + 1];
+    keep[0] = true;
+    keep[farthest] = true;
+    rdp_keep(&ring, 0, farthest, epsilon, &mut keep);
+    rdp_keep(&ring, farthest, point_count, epsilon, &mut keep);
+
+    (0..point_count)
+        .filter(|index| keep[*index])
+        .map(|index| points[index])
+        .collect()
+}
+
+/// Marks the vertices between `start` and `end` that the simplified chain needs.
+fn rdp_keep<T: Scalar>(
+    chain: &[Point<T>],
+    start: usize,
+    end: usize,
+    epsilon: T,
+    keep: &mut [bool],
+) {
+    let mut farthest = None;
+    let mut max_distance = epsilon;
+    for index in start + 1..end {
+        let distance =
+            point_to_segment_distance(&chain[index], &Line::new(chain[start], chain[end]));
+        if distance > max_distance {
+            max_distance = distance;
+            farthest = Some(index);
+        }
+    }
+
+    if let Some(index) = farthest {
+        keep[index] = true;
+        rdp_keep(chain, start, index, epsilon, keep);
+        rdp_keep(chain, index, end, epsilon, keep);
+    }
+}
+
+/// Splits the polygon into triangles by ear clipping.
+/// Works for concave polygons too, but the input must be simple,
+/// check with `is_simple` first if unsure. Both windings are accepted
+/// and the triangles are returned counter-clockwise.
+pub fn triangulate<T: Scalar>(points: &[Point<T>]) -> Vec<[Point<T>; 3]> {
+    let mut triangles = Vec::new();
+    if points.len() < 3 {
+        return triangles;
+    }
+
+    let mut remaining: Vec<usize> = (0..points.len()).collect();
+    if polygon_signed_area(points) < T::ZERO {
+        remaining.reverse();
+    }
+
+    let mut index = 0;
+    let mut attempts = 0;
+    while remaining.len() > 3 {
+        let count = remaining.len();
+        let prev = points[remaining[(index + count - 1) % count]];
+        let current = points[remaining[index % count]];
+        let next = points[remaining[(index + 1) % count]];
+
+        let turn = orient2d(&prev, &current, &next);
+        if turn == T::ZERO {
+            // Collinear vertex, removing it doesn't change the shape.
+            remaining.remove(index % count);
+            attempts = 0;
+            continue;
+        }
+
+        let is_ear = turn > T::ZERO
+            && !remaining.iter().any(|&other| {
+                let p = &points[other];
+                *p != prev
+                    && *p != current
+                    && *p != next
+                    && orient2d(&prev, &current, p) >= T::ZERO
+                    && orient2d(&current, &next, p) >= T::ZERO
+                    && orient2d(&next, &prev, p) >= T::ZERO
+            });
+
+        if is_ear {
+            triangles.push([prev, current, next]);
+            remaining.remove(index % count);
+            attempts = 0;
+        } else {
+            index = (index + 1) % count;
+            attempts += 1;
+            if attempts > count {
+                // No ear left, the polygon wasn't simple.
+                return triangles;
+            }
+        }
+    }
+
+    let last = [
+        points[remaining[0]],
+        points[remaining[1]],
+        points[remaining[2]],
+    ];
+    if orient2d(&last[0], &last[1], &last[2]) != T::ZERO {
+        triangles.push(last);
+    }
+    triangles
+}
+
+/// Convex hull of the points using Andrew's monotone chain,
+/// in counter-clockwise order starting from the lowest x (then y) point.
+/// Collinear points along the hull edges are left out. Fewer than three
+/// distinct points, or points all on one line, give the extreme points only.
+pub fn convex_hull<T: Scalar>(points: &[Point<T>]) -> Vec<Point<T>> {
+    let mut sorted = points.to_vec();
+    sorted.sort_by(|a, b| {
+        a.x.partial_cmp(&b.x)
+            .unwrap_or(std::cmp::Ordering::Equal)
+            .then(a.y.partial_cmp(&b.y).unwrap_or(std::cmp::Ordering::Equal))
+    });
+    sorted.dedup();
+    if sorted.len() < 3 {
+        return sorted;
+    }
+
+    let mut hull = hull_chain(sorted.iter());
+    hull.extend(hull_chain(sorted.iter().rev()));
+    hull
+}
+
+/// One half of the monotone chain: keeps only left turns, dropping points
+/// that make the chain turn right or continue straight on.
+/// The last point is left out since it starts the other half.
+fn hull_chain<'a, T: Scalar + 'a>(points: impl Iterator<Item = &'a Point<T>>) -> Vec<Point<T>> {
+    let mut chain: Vec<Point<T>> = Vec::new();
+    for point in points {
+        while chain.len() >= 2
+            && orient2d(&chain[chain.len() - 2], &chain[chain.len() - 1], point) <= T::ZERO
+        {
+            chain.pop();
+        }
+        chain.push(*point);
+    }
+    chain.pop();
+    chain
+}
+
+/// Twice the signed area of a polygon on the integer grid, computed exactly.
+/// Positive for counter-clockwise winding, negative for clockwise. The area
+/// of a lattice polygon is a multiple of one half, so it is left to the
+/// caller to divide by two, e.g. only when printing it.
+///
+/// With every coordinate within +-2^62 each term of the sum stays below 2^125,
+/// so it takes coordinates near the ends of the i64 range, or a very large
+/// number of vertices, to overflow an i128. None is returned then.
+pub fn polygon_area_exact(points: &[Point2i]) -> Option<i128> {
+    if points.len() < 3 {
+        return Some(0);
+    }
+    points
+        .iter()
+        .zip(points.iter().cycle().skip(1))
+        .try_fold(0_i128, |sum, (a, b)| {
+            // Each product fits, at most 2^126 in magnitude, their difference may not.
+            let term = (a.x as i128 * b.y as i128).checked_sub(b.x as i128 * a.y as i128)?;
+            sum.checked_add(term)
+        })
+}
+
+/// Signed area of the polygon, using the shoelace formula.
+/// Positive for counter-clockwise winding, negative for clockwise.
+/// A closing point repeating the first one is ignored.
+/// https://en.wikipedia.org/wiki/Shoelace_formula
+pub fn polygon_signed_area<T: Scalar>(points: &[Point<T>]) -> T {
+    let points = open_ring(points, T::EPSILON);
+    if points.len() < 3 {
+        return T::ZERO; // Not a polygon by definition.
+    }
+
+    // Measuring from the first point keeps the products small for polygons
+    // far from the origin, and compensated (Kahan) summation keeps the
+    // rounding error from growing with the number of points.
+    let origin = points[0];
+    let mut area = T::ZERO;
+    let mut compensation = T::ZERO;
+    for edge in edges(points) {
+        let current = edge.p1 - origin;
+        let next = edge.p2 - origin;
+
+        // Apply shoelace formula component
+        let term = current.cross(&next) - compensation;
+        let sum = area + term;
+        compensation = (sum - area) - term;
+        area = sum;
+    }
+
+    area / T::from_f64(2.0)
+}
+
+/// Area of the polygon regardless of winding.
+pub fn polygon_area<T: Scalar>(points: &[Point<T>]) -> T {
+    polygon_signed_area(points).abs()
+}
+
+/// True when the points wind counter-clockwise.
+/// Degenerate polygons with zero area count as clockwise.
+pub fn is_ccw<T: Scalar>(points: &[Point<T>]) -> bool {
+    polygon_signed_area(points) > T::ZERO
+}
+
+/// Reverses the points in place if they wind clockwise.
+pub fn ensure_ccw<T: Scalar>(points: &mut [Point<T>]) {
+    if polygon_signed_area(points) < T::ZERO {
+        points.reverse();
+    }
+}
+
+/// Rounds `value` to the given number of decimal places,
+/// e.g. to hide floating point noise when printing an area.
+pub fn round_to_decimals<T: Scalar>(value: T, decimals: i32) -> T {
+    let precision = T::from_f64(10f64.powi(decimals));
+    (value * precision).round() / precision
+}
+
+/// Rounds an area for display. The area functions themselves never round,
+/// so results stay exact when they feed further computation.
+pub fn round_area<T: Scalar>(value: T, decimals: u32) -> T {
+    round_to_decimals(value, decimals as i32)
+}
+
+/// Sums the lengths of all edges, including the closing edge
+/// from the last point back to the first.
+/// A two point "polygon" is treated as a closed ring, so its perimeter
+/// is twice the length of the segment.
+pub fn polygon_perimeter<T: Scalar>(points: &[Point<T>]) -> T {
+    if points.len() < 2 {
+        return T::ZERO;
+    }
+
+    edges(points).map(|edge| edge.length()).sum()
+}
+
+/// Area weighted centroid built on the same cross products as the shoelace formula.
+/// https://en.wikipedia.org/wiki/Centroid#Of_a_polygon
+/// Returns None for degenerate polygons without area.
+pub fn polygon_centroid<T: Scalar>(points: &[Point<T>]) -> Option<Point<T>> {
+    if points.len() < 3 {
+        return None;
+    }
+
+    let mut signed_area = T::ZERO;
+    let mut centroid_x = T::ZERO;
+    let mut centroid_y = T::ZERO;
+    for edge in edges(points) {
+        let (current, next) = (&edge.p1, &edge.p2);
+
+        let cross = current.x * next.y - next.x * current.y;
+        signed_area += cross;
+        centroid_x += (current.x + next.x) * cross;
+        centroid_y += (current.y + next.y) * cross;
+    }
+    signed_area /= T::from_f64(2.0);
+
+    if signed_area.abs() < T::EPSILON {
+        return None;
+    }
+
+    // The winding direction flips the sign of every term, so it cancels out here.
+    Some(Point {
+        x: centroid_x / (T::from_f64(6.0) * signed_area),
+        y: centroid_y / (T::from_f64(6.0) * signed_area),
+    })
+}
+
+/// Shortest distance from `p` to the segment, measured to the nearest
+/// end point when the perpendicular foot falls outside of it.
+pub fn point_to_segment_distance<T: Scalar>(p: &Point<T>, seg: &Line<T>) -> T {
+    p.distance(&closest_point_on_segment(p, seg))
+}
+
+/// Point on the segment nearest to `p`.
+fn closest_point_on_segment<T: Scalar>(p: &Point<T>, seg: &Line<T>) -> Point<T> {
+    if seg.p1 == seg.p2 {
+        return seg.p1;
+    }
+
+    let t = line_parameter(seg, p).max(T::ZERO).min(T::ONE);
+    seg.point_at(t)
+}
+
+/// Point on the polygon boundary nearest to `query`, together with the index
+/// of the edge it lies on. Edge `i` runs from `points[i]` to the next point.
+/// Returns None if there are fewer than three points.
+pub fn closest_boundary_point<T: Scalar>(
+    points: &[Point<T>],
+    query: &Point<T>,
+) -> Option<(Point<T>, usize)> {
+    if points.len() < 3 {
+        return None;
+    }
+
+    edges(points)
+        .enumerate()
+        .map(|(index, edge)| (point_to_segment_distance(query, &edge), index, edge))
+        .min_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal))
+        .map(|(_, index, edge)| (closest_point_on_segment(query, &edge), index))
+}
+
+/// Checks whether `p` lies on the segment, within `epsilon` distance.
+pub fn point_on_edge<T: Scalar>(p: &Point<T>, seg: &Line<T>, epsilon: T) -> bool {
+    point_to_segment_distance(p, seg) < epsilon
+}
+
+/// Ray casting (crossing number) point in polygon test.
+/// https://en.wikipedia.org/wiki/Point_in_polygon#Ray_casting_algorithm
+/// Points on the boundary (within +-EPSILON) count as inside,
+/// so a point on a cut belongs to both fragments sharing it.
+pub fn point_in_polygon<T: Scalar>(points: &[Point<T>], p: &Point<T>) -> bool {
+    if points.len() < 3 {
+        return false;
+    }
+
+    let mut inside = false;
+    for edge in edges(points) {
+        if point_on_edge(p, &edge, T::EPSILON) {
+            return true;
+        }
+
+        // Count the edges crossed by a ray going right from p.
+        // Half open comparison so a vertex on the ray is only counted once.
+        let (current, next) = (&edge.p1, &edge.p2);
+        if (current.y > p.y) != (next.y > p.y) {
+            let crossing_x =
+                current.x + (p.y - current.y) * (next.x - current.x) / (next.y - current.y);
+            if p.x < crossing_x {
+                inside = !inside;
+            }
+        }
+    }
+
+    inside
+}
+
+/// A polygon that may have holes in it.
+/// Holes are meant to wind opposite to the exterior, but either winding
+/// is accepted since the methods normalize it.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Polygon<T = f64> {
+    pub exterior: Vec<Point<T>>,
+    pub holes: Vec<Vec<Point<T>>>,
+}
+
+impl<T: Scalar> Polygon<T> {
+    /// Polygon without any holes.
+    pub fn new(exterior: Vec<Point<T>>) -> Self {
+        Polygon {
+            exterior,
+            holes: Vec::new(),
+        }
+    }
+
+    pub fn with_holes(exterior: Vec<Point<T>>, holes: Vec<Vec<Point<T>>>) -> Self {
+        Polygon { exterior, holes }
+    }
+
+    /// Area of the exterior minus the area of all holes.
+    pub fn net_area(&self) -> T {
+        // polygon_area is unsigned, which takes care of the winding.
+        let hole_area: T = self.holes.iter().map(|hole| polygon_area(hole)).sum();
+        polygon_area(&self.exterior) - hole_area
+    }
+
+    /// Same as `net_area`.
+    pub fn area(&self) -> T {
+        self.net_area()
+    }
+
+    /// Length of the exterior plus the length around every hole.
+    pub fn perimeter(&self) -> T {
+        let hole_perimeter: T = self.holes.iter().map(|hole| polygon_perimeter(hole)).sum();
+        polygon_perimeter(&self.exterior) + hole_perimeter
+    }
+
+    /// Area weighted centroid of what is left after cutting out the holes,
+    /// which can lie inside a hole. Returns None when there is no area left.
+    pub fn centroid(&self) -> Option<Point<T>> {
+        let area = self.net_area();
+        if area < T::EPSILON {
+            return None;
+        }
+
+        // Each hole's centroid pulls against the exterior's by the area it removes.
+        let mut moment = polygon_centroid(&self.exterior)? * polygon_area(&self.exterior);
+        for hole in &self.holes {
+            if let Some(centroid) = polygon_centroid(hole) {
+                moment = moment - centroid * polygon_area(hole);
+            }
+        }
+        Some(moment / area)
+    }
+
+    /// Whether the point is inside the exterior and not inside any hole,
+    /// see `point_in_polygon`.
+    pub fn contains(&self, p: &Point<T>) -> bool {
+        point_in_polygon(&self.exterior, p)
+            && !self.holes.iter().any(|hole| point_in_polygon(hole, p))
+    }
+}
+
+/// A polygon produced by slicing, together with its area.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Fragment<T = f64> {
+    pub points: Vec<Point<T>>,
+    pub area: T,
+}
+
+/// Splits polygon into smaller polygons by a list of lines, applied in order.
+/// Returns every resulting polygon. Each cut replaces a polygon with its pieces
+/// at the same position, so the order is stable for a given input.
+/// A polygon that a line doesn't cut is kept as it is. Any other reason
+/// `split_polygon` gives for not cutting, e.g. a zero length line, is returned
+/// as the error instead of being passed over. The polygon and every line are
+/// checked for NaN and infinite coordinates first, see `validate_polygon`.
+/// `SliceOptions::slice_mode` can make every line cut the original polygon instead.
+pub fn get_all_fragments<T: Scalar>(
+    polygon_points: &[Point<T>],
+    lines: &[Line<T>],
+) -> Result<Vec<Vec<Point<T>>>, SliceError> {
+    get_all_fragments_with_options(polygon_points, lines, &SliceOptions::default())
+}
+
+/// Same as `get_all_fragments` but with custom options.
+pub fn get_all_fragments_with_options<T: Scalar>(
+    polygon_points: &[Point<T>],
+    lines: &[Line<T>],
+    options: &SliceOptions<T>,
+) -> Result<Vec<Vec<Point<T>>>, SliceError> {
+    validate_input(polygon_points, lines)?;
+    let original = vec![polygon_points.to_vec()];
+    match options.slice_mode {
+        SliceMode::Sequential => lines.iter().try_fold(original, |polygons, line| {
+            cut_fragments(&polygons, line, options)
+        }),
+        SliceMode::Independent if lines.is_empty() => Ok(original),
+        SliceMode::Independent => {
+            let mut fragments = Vec::new();
+            for line in lines {
+                fragments.append(&mut cut_fragments(&original, line, options)?);
+            }
+            Ok(fragments)
+        }
+    }
+}
+
+/// Splits the polygon by the line, or keeps it whole if the line doesn't cut it.
+fn split_or_keep<T: Scalar>(
+    poly: &[Point<T>],
+    line: &Line<T>,
+    options: &SliceOptions<T>,
+) -> Result<Vec<Vec<Point<T>>>, SliceError> {
+    match split_polygon_with_options(poly, line, options) {
+        Err(SliceError::NoIntersection) => Ok(vec![poly.to_vec()]),
+        result => result,
+    }
+}
+
+/// How many pieces `split_or_keep` gives, without tracing them.
+fn count_split_or_keep<T: Scalar>(
+    poly: &[Point<T>],
+    line: &Line<T>,
+    options: &SliceOptions<T>,
+) -> Result<usize, SliceError> {
+    match cut_spans(poly, line, options) {
+        Ok((_, spans)) => Ok(spans.len() + 1),
+        Err(SliceError::NoIntersection) => Ok(1),
+        Err(error) => Err(error),
+    }
+}
+
+/// One step of `get_all_fragments`: cuts every polygon by the line,
+/// keeping the ones it doesn't cut as they are.
+#[cfg(not(feature = "parallel"))]
+fn cut_fragments<T: Scalar>(
+    polygons: &[Vec<Point<T>>],
+    line: &Line<T>,
+    options: &SliceOptions<T>,
+) -> Result<Vec<Vec<Point<T>>>, SliceError> {
+    let mut new_polygons = Vec::new();
+    for poly in polygons {
+        new_polygons.append(&mut split_or_keep(poly, line, options)?);
+    }
+    Ok(new_polygons)
+}
+
+/// Same as the serial `cut_fragments`, but splits the polygons on all cores.
+/// The pieces are collected in the same order as the serial version.
+#[cfg(feature = "parallel")]
+fn cut_fragments<T: Scalar>(
+    polygons: &[Vec<Point<T>>],
+    line: &Line<T>,
+    options: &SliceOptions<T>,
+) -> Result<Vec<Vec<Point<T>>>, SliceError> {
+    use rayon::prelude::*;
+
+    let pieces = polygons
+        .par_iter()
+        .map(|poly| split_or_keep(poly, line, options))
+        .collect::<Result<Vec<_>, SliceError>>()?;
+    Ok(pieces.into_iter().flatten().collect())
+}
+
+/// Cuts a polygon one line at a time, keeping the fragments in between,
+/// e.g. to show the result after every line the user draws. Adding the
+/// lines in order gives the same fragments as `get_all_fragments`.
+#[derive(Debug, Clone)]
+pub struct Slicer<T = f64> {
+    fragments: Vec<Vec<Point<T>>>,
+    options: SliceOptions<T>,
+    /// Fragments from before each cut, most recent last.
+    history: VecDeque<Vec<Vec<Point<T>>>>,
+}
+
+impl<T> Slicer<T> {
+    /// Number of cuts `undo` can go back, older states are forgotten.
+    pub const UNDO_LIMIT: usize = 64;
+}
+
+impl<T: Scalar> Slicer<T> {
+    pub fn new(polygon_points: Vec<Point<T>>) -> Self {
+        Slicer::with_options(polygon_points, SliceOptions::default())
+    }
+
+    /// Same as `new` but with custom options. Each cut is applied to the
+    /// current fragments, so `options.slice_mode` is not used.
+    pub fn with_options(polygon_points: Vec<Point<T>>, options: SliceOptions<T>) -> Self {
+        Slicer {
+            fragments: vec![polygon_points],
+            options,
+            history: VecDeque::new(),
+        }
+    }
+
+    /// Cuts every current fragment by the line. On error the fragments
+    /// are left as they were, see `get_all_fragments`.
+    pub fn add_cut(&mut self, line: &Line<T>) -> Result<(), SliceError> {
+        let cut = cut_fragments(&self.fragments, line, &self.options)?;
+        if self.history.len() == Self::UNDO_LIMIT {
+            self.history.pop_front();
+        }
+        self.history
+            .push_back(std::mem::replace(&mut self.fragments, cut));
+        Ok(())
+    }
+
+    /// Goes back to the fragments from before the most recent `add_cut`.
+    /// Does nothing when there is no cut left to undo.
+    pub fn undo(&mut self) {
+        if let Some(fragments) = self.history.pop_back() {
+            self.fragments = fragments;
+        }
+    }
+
+    pub fn fragments(&self) -> &[Vec<Point<T>>] {
+        &self.fragments
+    }
+
+    /// Area of the largest current fragment.
+    pub fn largest_area(&self) -> T {
+        self.fragments
+            .iter()
+            .map(|poly| polygon_area(poly))
+            .reduce(T::max)
+            .unwrap_or(T::ZERO)
+    }
+}
+
+/// Pairs of fragment indices `(i, j)`, with `i < j`, whose outlines share
+/// part of an edge, i.e. the dual graph of the fragments. Fragments that
+/// only touch in a corner are not neighbours.
+pub fn fragment_adjacency<T: Scalar>(fragments: &[Vec<Point<T>>]) -> Vec<(usize, usize)> {
+    let mut pairs = Vec::new();
+    for (i, first) in fragments.iter().enumerate() {
+        for (j, second) in fragments.iter().enumerate().skip(i + 1) {
+            let shares_edge = edges(first).any(|a| {
+                edges(second)
+                    .any(|b| matches!(segment_intersection(&a, &b), Intersection::Collinear(..)))
+            });
+            if shares_edge {
+                pairs.push((i, j));
+            }
+        }
+    }
+    pairs
+}
+
+/// Joins two neighbouring polygons into one by removing the edge they share,
+/// undoing a cut. The shared edge has to run between the same two points
+/// (within `epsilon`) in both polygons, as it does for the two pieces of a
+/// single cut. The shared edge's end points are dropped as well if they end
+/// up in the middle of a straight edge. Returns None unless exactly one such
+/// edge is shared. The result is counter-clockwise.
+pub fn merge_fragments<T: Scalar>(
+    a: &[Point<T>],
+    b: &[Point<T>],
+    epsilon: T,
+) -> Option<Vec<Point<T>>> {
+    if a.len() < 3 || b.len() < 3 {
+        return None;
+    }
+    let mut a = a.to_vec();
+    let mut b = b.to_vec();
+    ensure_ccw(&mut a);
+    ensure_ccw(&mut b);
+
+    // With both counter-clockwise the shared edge runs the other way in `b`.
+    let next = |points: &[Point<T>], index: usize| points[(index + 1) % points.len()];
+    let mut shared = (0..a.len())
+        .flat_map(|i| (0..b.len()).map(move |j| (i, j)))
+        .filter(|&(i, j)| {
+            points_within(&a[i], &next(&b, j), epsilon)
+                && points_within(&next(&a, i), &b[j], epsilon)
+        });
+    let (i, j) = shared.next()?;
+    if shared.next().is_some() {
+        return None;
+    }
+
+    // From the end of the shared edge all the way around `a` to its start,
+    // then around `b` back to where it meets the shared edge again.
+    let mut merged: Vec<Point<T>> = (1..=a.len()).map(|k| a[(i + k) % a.len()]).collect();
+    merged.extend((2..b.len()).map(|k| b[(j + k) % b.len()]));
+
+    // The shared edge's ends are the first and last points of `a`'s part.
+    let collinear_at = |points: &[Point<T>], index: usize| {
+        let count = points.len();
+        let prev = &points[(index + count - 1) % count];
+        let next = &points[(index + 1) % count];
+        orient2d(prev, &points[index], next).abs() <= epsilon * prev.distance(next)
+    };
+    let last_of_a = a.len() - 1;
+    if collinear_at(&merged, last_of_a) {
+        merged.remove(last_of_a);
+    }
+    if collinear_at(&merged, 0) {
+        merged.remove(0);
+    }
+
+    Some(merged)
+}
+
+/// Splits the polygon into convex pieces, following Hertel-Mehlhorn:
+/// triangulate it, then join neighbouring pieces as long as the result stays convex.
+/// Gives at most four times as many pieces as the fewest possible.
+/// The input must be simple, as for `triangulate`. The pieces are counter-clockwise.
+pub fn decompose_convex<T: Scalar>(points: &[Point<T>]) -> Vec<Vec<Point<T>>> {
+    let mut pieces: Vec<Vec<Point<T>>> = triangulate(points)
+        .into_iter()
+        .map(|triangle| triangle.to_vec())
+        .collect();
+
+    let mut merged_any = true;
+    while merged_any {
+        merged_any = false;
+        'search: for i in 0..pieces.len() {
+            for j in i + 1..pieces.len() {
+                let Some(merged) = merge_fragments(&pieces[i], &pieces[j], T::EPSILON) else {
+                    continue;
+                };
+                if is_convex(&merged) {
+                    pieces[i] = merged;
+                    pieces.remove(j);
+                    merged_any = true;
+                    break 'search;
+                }
+            }
+        }
+    }
+    pieces
+}
+
+/// Drops fragments whose area is below `min_area`.
+/// Cuts passing just beside a vertex leave behind slivers
+/// that are only floating point noise, this gets rid of them.
+pub fn remove_slivers<T: Scalar>(fragments: Vec<Vec<Point<T>>>, min_area: T) -> Vec<Vec<Point<T>>> {
+    fragments
+        .into_iter()
+        .filter(|fragment| polygon_area(fragment) >= min_area)
+        .collect()
+}
+
+/// Splits polygon into smaller polygons by a list of lines.
+/// Returns the largest polygon found along with its area.
+pub fn get_largest_polygon<T: Scalar>(
+    polygon_points: &[Point<T>],
+    lines: &[Line<T>],
+) -> Result<Fragment<T>, SliceError> {
+    get_largest_polygon_with_options(polygon_points, lines, &SliceOptions::default())
+}
+
+/// Same as `get_largest_polygon` but with custom options.
+pub fn get_largest_polygon_with_options<T: Scalar>(
+    polygon_points: &[Point<T>],
+    lines: &[Line<T>],
+    options: &SliceOptions<T>,
+) -> Result<Fragment<T>, SliceError> {
+    let mut largest = Fragment {
+        points: Vec::new(),
+        area: T::ZERO,
+    };
+
+    // find largest polygon
+    for poly in get_all_fragments_with_options(polygon_points, lines, options)? {
+        let area = polygon_area(&poly);
+        if largest.points.is_empty() || area > largest.area {
+            largest = Fragment { points: poly, area };
+        }
+    }
+
+    Ok(largest)
+}
+
+/// Splits polygon into smaller polygons by a list of lines.
+/// Returns the area of the largest polygon found.
+pub fn get_largest_polygon_area<T: Scalar>(
+    polygon_points: &[Point<T>],
+    lines: &[Line<T>],
+) -> Result<T, SliceError> {
+    get_largest_polygon_area_with_options(polygon_points, lines, &SliceOptions::default())
+}
+
+/// Same as `get_largest_polygon_area` but with custom options.
+pub fn get_largest_polygon_area_with_options<T: Scalar>(
+    polygon_points: &[Point<T>],
+    lines: &[Line<T>],
+    options: &SliceOptions<T>,
+) -> Result<T, SliceError> {
+    Ok(get_largest_polygon_with_options(polygon_points, lines, options)?.area)
+}
+
+/// Splits polygon into smaller polygons by a list of lines, applied in order.
+/// Returns the fragment areas after each cut, so the first entry holds the
+/// areas after `lines[0]`, the second after `lines[1]` and so on.
+pub fn get_areas_per_cut<T: Scalar>(
+    polygon_points: &[Point<T>],
+    lines: &[Line<T>],
+) -> Result<Vec<Vec<T>>, SliceError> {
+    get_areas_per_cut_with_options(polygon_points, lines, &SliceOptions::default())
+}
+
+/// Same as `get_areas_per_cut` but with custom options.
+pub fn get_areas_per_cut_with_options<T: Scalar>(
+    polygon_points: &[Point<T>],
+    lines: &[Line<T>],
+    options: &SliceOptions<T>,
+) -> Result<Vec<Vec<T>>, SliceError> {
+    validate_input(polygon_points, lines)?;
+    let original = vec![polygon_points.to_vec()];
+    let mut polygons = original.clone();
+    lines
+        .iter()
+        .map(|line| {
+            polygons = match options.slice_mode {
+                SliceMode::Sequential => cut_fragments(&polygons, line, options)?,
+                SliceMode::Independent => cut_fragments(&original, line, options)?,
+            };
+            Ok(polygons.iter().map(|poly| polygon_area(poly)).collect())
+        })
+        .collect()
+}
+
+/// Splits polygon into smaller polygons by a list of lines.
+/// Returns the area of every fragment, in the same order as `get_all_fragments`.
+pub fn get_all_areas<T: Scalar>(
+    polygon_points: &[Point<T>],
+    lines: &[Line<T>],
+) -> Result<Vec<T>, SliceError> {
+    get_all_areas_with_options(polygon_points, lines, &SliceOptions::default())
+}
+
+/// Same as `get_all_areas` but with custom options.
+pub fn get_all_areas_with_options<T: Scalar>(
+    polygon_points: &[Point<T>],
+    lines: &[Line<T>],
+    options: &SliceOptions<T>,
+) -> Result<Vec<T>, SliceError> {
+    Ok(
+        get_all_fragments_with_options(polygon_points, lines, options)?
+            .iter()
+            .map(|poly| polygon_area(poly))
+            .collect(),
+    )
+}
+
+/// Splits polygon into smaller polygons by a list of lines.
+/// Returns the area of the smallest polygon found.
+pub fn get_smallest_polygon_area<T: Scalar>(
+    polygon_points: &[Point<T>],
+    lines: &[Line<T>],
+) -> Result<T, SliceError> {
+    get_smallest_polygon_area_with_options(polygon_points, lines, &SliceOptions::default())
+}
+
+/// Same as `get_smallest_polygon_area` but with custom options.
+pub fn get_smallest_polygon_area_with_options<T: Scalar>(
+    polygon_points: &[Point<T>],
+    lines: &[Line<T>],
+    options: &SliceOptions<T>,
+) -> Result<T, SliceError> {
+    Ok(get_all_areas_with_options(polygon_points, lines, options)?
+        .into_iter()
+        .reduce(T::min)
+        .unwrap_or(T::ZERO))
+}
+
+/// Splits polygon into smaller polygons by a list of lines.
+/// Returns how many polygons there are in the end, counting the ones
+/// no line could cut, so a polygon missed by every line gives 1.
+/// The pieces of the last cut, or of every cut with `SliceMode::Independent`,
+/// are only counted and never built.
+pub fn fragment_count<T: Scalar>(
+    polygon_points: &[Point<T>],
+    lines: &[Line<T>],
+) -> Result<usize, SliceError> {
+    fragment_count_with_options(polygon_points, lines, &SliceOptions::default())
+}
+
+/// Same as `fragment_count` but with custom options.
+pub fn fragment_count_with_options<T: Scalar>(
+    polygon_points: &[Point<T>],
+    lines: &[Line<T>],
+    options: &SliceOptions<T>,
+) -> Result<usize, SliceError> {
+    validate_input(polygon_points, lines)?;
+    let original = vec![polygon_points.to_vec()];
+    let (polygons, counted_lines) = match options.slice_mode {
+        SliceMode::Sequential => {
+            let Some((last, lines)) = lines.split_last() else {
+                return Ok(1);
+            };
+            let polygons = lines.iter().try_fold(original, |polygons, line| {
+                cut_fragments(&polygons, line, options)
+            })?;
+            (polygons, std::slice::from_ref(last))
+        }
+        SliceMode::Independent if lines.is_empty() => return Ok(1),
+        SliceMode::Independent => (original, lines),
+    };
+
+    let mut count = 0;
+    for line in counted_lines {
+        for poly in &polygons {
+            count += count_split_or_keep(poly, line, options)?;
+        }
+    }
+    Ok(count)
+}
+
+/// Splits polygon into smaller polygons by a list of lines.
+/// Returns the `k`-th largest area, counting from 0, so `k = 0` is the
+/// same as `get_largest_polygon_area` and `k = 1` the second largest.
+/// None if there are `k` or fewer fragments.
+pub fn nth_largest_area<T: Scalar>(
+    polygon_points: &[Point<T>],
+    lines: &[Line<T>],
+    k: usize,
+) -> Result<Option<T>, SliceError> {
+    nth_largest_area_with_options(polygon_points, lines, k, &SliceOptions::default())
+}
+
+/// Same as `nth_largest_area` but with custom options.
+pub fn nth_largest_area_with_options<T: Scalar>(
+    polygon_points: &[Point<T>],
+    lines: &[Line<T>],
+    k: usize,
+    options: &SliceOptions<T>,
+) -> Result<Option<T>, SliceError> {
+    let mut areas = get_all_areas_with_options(polygon_points, lines, options)?;
+    areas.sort_by(|a, b| b.partial_cmp(a).unwrap_or(std::cmp::Ordering::Equal));
+    Ok(areas.get(k).copied())
+}
+
+/// Splits polygon into smaller polygons by a list of lines.
+/// Returns the median fragment area, the mean of the two middle areas
+/// for an even number of fragments. None if there are no fragments.
+pub fn median_area<T: Scalar>(
+    polygon_points: &[Point<T>],
+    lines: &[Line<T>],
+) -> Result<Option<T>, SliceError> {
+    median_area_with_options(polygon_points, lines, &SliceOptions::default())
+}
+
+/// Same as `median_area` but with custom options.
+pub fn median_area_with_options<T: Scalar>(
+    polygon_points: &[Point<T>],
+    lines: &[Line<T>],
+    options: &SliceOptions<T>,
+) -> Result<Option<T>, SliceError> {
+    let mut areas = get_all_areas_with_options(polygon_points, lines, options)?;
+    areas.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+    let middle = areas.len() / 2;
+    Ok(match areas.len() {
+        0 => None,
+        len if len % 2 == 1 => Some(areas[middle]),
+        _ => Some((areas[middle - 1] + areas[middle]) / T::from_f64(2.0)),
+    })
+}
+
+/// A fragment along with the index of the polygon it was cut from.
+pub type IndexedFragment<T = f64> = (usize, Vec<Point<T>>);
+
+/// Cuts several polygons by the same list of lines, each one on its own
+/// as in `get_all_fragments`. Returns the fragments of every polygon,
+/// in the order of the polygons, each with the index of the polygon it came from.
+pub fn get_all_fragments_multi<T: Scalar>(
+    polygons: &[Vec<Point<T>>],
+    lines: &[Line<T>],
+) -> Result<Vec<IndexedFragment<T>>, SliceError> {
+    get_all_fragments_multi_with_options(polygons, lines, &SliceOptions::default())
+}
+
+/// Same as `get_all_fragments_multi` but with custom options.
+pub fn get_all_fragments_multi_with_options<T: Scalar>(
+    polygons: &[Vec<Point<T>>],
+    lines: &[Line<T>],
+    options: &SliceOptions<T>,
+) -> Result<Vec<IndexedFragment<T>>, SliceError> {
+    let mut fragments = Vec::new();
+    for (index, polygon) in polygons.iter().enumerate() {
+        for fragment in get_all_fragments_with_options(polygon, lines, options)? {
+            fragments.push((index, fragment));
+        }
+    }
+    Ok(fragments)
+}
+
+/// Cuts several polygons by the same list of lines, see `get_all_fragments_multi`.
+/// Returns the area of every fragment.
+pub fn get_all_areas_multi<T: Scalar>(
+    polygons: &[Vec<Point<T>>],
+    lines: &[Line<T>],
+) -> Result<Vec<T>, SliceError> {
+    get_all_areas_multi_with_options(polygons, lines, &SliceOptions::default())
+}
+
+/// Same as `get_all_areas_multi` but with custom options.
+pub fn get_all_areas_multi_with_options<T: Scalar>(
+    polygons: &[Vec<Point<T>>],
+    lines: &[Line<T>],
+    options: &SliceOptions<T>,
+) -> Result<Vec<T>, SliceError> {
+    Ok(
+        get_all_fragments_multi_with_options(polygons, lines, options)?
+            .iter()
+            .map(|(_, poly)| polygon_area(poly))
+            .collect(),
+    )
+}
+
+/// Cuts several polygons by the same list of lines, see `get_all_fragments_multi`.
+/// Returns the area of the largest fragment of any of them.
+pub fn get_largest_polygon_area_multi<T: Scalar>(
+    polygons: &[Vec<Point<T>>],
+    lines: &[Line<T>],
+) -> Result<T, SliceError> {
+    get_largest_polygon_area_multi_with_options(polygons, lines, &SliceOptions::default())
+}
+
+/// Same as `get_largest_polygon_area_multi` but with custom options.
+pub fn get_largest_polygon_area_multi_with_options<T: Scalar>(
+    polygons: &[Vec<Point<T>>],
+    lines: &[Line<T>],
+    options: &SliceOptions<T>,
+) -> Result<T, SliceError> {
+    Ok(get_all_areas_multi_with_options(polygons, lines, options)?
+        .into_iter()
+        .reduce(T::max)
+        .unwrap_or(T::ZERO))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        area_bisector_through, bounding_box, classify_crossings, clean_polygon, clip_polygon,
+        clip_to_rect, closest_boundary_point, convex_hull, cut_chords, decompose_convex, edges,
+        edges_crossed, edges_crossed_with_options, ensure_ccw, find_intersections,
+        find_intersections_with_options, fragment_adjacency, fragment_count,
+        fragment_count_with_options, get_all_areas, get_all_areas_multi,
+        get_all_areas_with_options, get_all_fragments, get_all_fragments_multi,
+        get_all_fragments_with_options, get_areas_per_cut, get_areas_per_cut_with_options,
+        get_largest_polygon, get_largest_polygon_area, get_largest_polygon_area_multi,
+        get_largest_polygon_area_with_options, get_smallest_polygon_area, halfplane_areas,
+        interior_angles, intersection_area, intersection_params, is_ccw, is_convex, is_simple,
+        line_segment_intersection, median_area, merge_fragments, min_enclosing_circle,
+        nth_largest_area, point_in_polygon, point_on_edge, point_to_segment_distance, points_equal,
+        polygon_area, polygon_area_exact, polygon_centroid, polygon_diameter, polygon_eq,
+        polygon_perimeter, polygon_signed_area, reflect_polygon, reflex_vertices,
+        remove_collinear_vertices, remove_slivers, round_area, round_to_decimals,
+        segment_intersection, simplify_rdp, snap_to_grid, split_polygon, split_polygon_by_chain,
+        split_polygon_with_options, total_cut_length, triangulate, union_convex, validate_line,
+        validate_polygon, CutMode, Intersection, Line, LineF32, Point, Point2i, PointF32, Polygon,
+        QuantizedPoint, SliceError, SliceMode, SliceOptions, Slicer, EPSILON,
+    };
+
+    #[test]
+    fn sample() {
+        let polygon_points = vec![
+            Point { x: 0.0, y: 0.0 },
+            Point { x: 1.0, y: 0.0 },
+            Point { x: 1.0, y: 1.0 },
+            Point { x: 0.0, y: 1.0 },
+        ];
+        let lines = vec![
+            Line {
+                p1: Point { x: 0.0, y: 0.0 },
+                p2: Point { x: 1.0, y: 1.0 },
+            },
+            Line {
+                p1: Point { x: 0.5, y: 0.0 },
+                p2: Point { x: 0.5, y: 1.0 },
+            },
+        ];
+
+        assert_eq!(
+            round_area(
+                get_largest_polygon_area(&polygon_points, &lines).unwrap(),
+                6
+            ),
+            0.375
+        );
+    }
+
+    #[test]
+    fn extra_no_cut() {
+        let polygon_points = vec![
+            Point { x: 0.0, y: 0.0 },
+            Point { x: 1.0, y: 0.0 },
+            Point { x: 1.0, y: 1.0 },
+            Point { x: 0.0, y: 1.0 },
+        ];
+        let lines = vec![Line {
+            p1: Point { x: 2.0, y: 2.0 },
+            p2: Point { x: 3.0, y: 3.0 },
+        }];
+
+        assert_eq!(
+            round_area(
+                get_largest_polygon_area(&polygon_points, &lines).unwrap(),
+                6
+            ),
+            1.0
+        );
+    }
+
+    #[test]
+    fn extra_intersect_one_vertex() {
+        let polygon_points = vec![
+            Point { x: 0.0, y: 0.0 },
+            Point { x: 1.0, y: 0.0 },
+            Point { x: 1.0, y: 1.0 },
+            Point { x: 0.0, y: 1.0 },
+        ];
+        let lines = vec![Line {
+            p1: Point { x: 2.0, y: 2.0 },
+            p2: Point { x: 0.5, y: 0.5 },
+        }];
+
+        assert_eq!(
+            round_area(
+                get_largest_polygon_area(&polygon_points, &lines).unwrap(),
+                6
+            ),
+            1.0
+        );
+    }
+
+    #[test]
+    fn extra_simple_1() {
+        let polygon_points = vec![
+            Point { x: 0.0, y: 0.0 },
+            Point { x: 1.0, y: 0.0 },
+            Point { x: 1.0, y: 1.0 },
+            Point { x: 0.0, y: 1.0 },
+        ];
+        let lines = vec![Line {
+            p1: Point { x: 0.5, y: 0.0 },
+            p2: Point { x: 0.5, y: 1.0 },
+        }];
+
+        assert_eq!(
+            round_area(
+                get_largest_polygon_area(&polygon_points, &lines).unwrap(),
+                6
+            ),
+            0.5
+        );
+    }
+
+    #[test]
+    fn extra_simple_2() {
+        let polygon_points = vec![
+            Point { x: 0.0, y: 0.0 },
+            Point { x: 1.0, y: 0.0 },
+            Point { x: 1.0, y: 1.0 },
+            Point { x: 0.0, y: 1.0 },
+        ];
+        let lines = vec![
+            Line {
+                p1: Point { x: 0.5, y: 0.0 },
+                p2: Point { x: 0.5, y: 1.0 },
+            },
+            Line {
+                p1: Point { x: 0.0, y: 0.5 },
+                p2: Point { x: 1.0, y: 0.5 },
+            },
+        ];
+
+        assert_eq!(
+            round_area(
+                get_largest_polygon_area(&polygon_points, &lines).unwrap(),
+                6
+            ),
+            0.25
+        );
+    }
+
+    #[test]
+    fn extra_two_double_intersects_diagonal() {
+        let polygon_points = vec![
+            Point { x: 0.0, y: 0.0 },
+            Point { x: 1.0, y: 0.0 },
+            Point { x: 1.0, y: 1.0 },
+            Point { x: 0.0, y: 1.0 },
+        ];
+        let lines = vec![Line {
+            p1: Point { x: 0.0, y: 0.0 },
+            p2: Point { x: 1.0, y: 1.0 },
+        }];
+
+        assert_eq!(
+            round_area(
+                get_largest_polygon_area(&polygon_points, &lines).unwrap(),
+                6
+            ),
+            0.5
+        );
+    }
+
+    #[test]
+    fn extra_intersect_partly_diagonal() {
+        let polygon_points = vec![
+            Point { x: 0.0, y: 0.0 },
+            Point { x: 1.0, y: 0.0 },
+            Point { x: 1.0, y: 1.0 },
+            Point { x: 0.0, y: 1.0 },
+        ];
+        let lines = vec![Line {
+            p1: Point { x: 0.1, y: 0.0 },
+            p2: Point { x: 1.0, y: 1.0 },
+        }];
+
+        assert_eq!(
+            round_area(
+                get_largest_polygon_area(&polygon_points, &lines).unwrap(),
+                6
+            ),
+            0.55
+        );
+    }
+
+    #[test]
+    fn constructors() {
+        assert_eq!(Point::new(1.0, 2.0), Point { x: 1.0, y: 2.0 });
+
+        let line = Line::from_coords(0.0, 0.5, 1.0, 0.5);
+        assert_eq!(line.p1, Point::new(0.0, 0.5));
+        assert_eq!(line.p2, Point::new(1.0, 0.5));
+
+        let polygon_points = vec![
+            Point::new(0.0, 0.0),
+            Point::new(1.0, 0.0),
+            Point::new(1.0, 1.0),
+            Point::new(0.0, 1.0),
+        ];
+        assert_eq!(
+            round_area(
+                get_largest_polygon_area(&polygon_points, &[line]).unwrap(),
+                6
+            ),
+            0.5
+        );
+    }
+
+    #[test]
+    fn concave_e_shape_multiple_fragments() {
+        // "E" with three prongs pointing right, each 2 wide and 1 tall.
+        let polygon_points = vec![
+            Point::new(0.0, 0.0),
+            Point::new(3.0, 0.0),
+            Point::new(3.0, 1.0),
+            Point::new(1.0, 1.0),
+            Point::new(1.0, 2.0),
+            Point::new(3.0, 2.0),
+            Point::new(3.0, 3.0),
+            Point::new(1.0, 3.0),
+            Point::new(1.0, 4.0),
+            Point::new(3.0, 4.0),
+            Point::new(3.0, 5.0),
+            Point::new(0.0, 5.0),
+        ];
+        let line = Line::from_coords(2.0, -1.0, 2.0, 6.0);
+
+        let fragments = split_polygon(&polygon_points, &line).unwrap();
+        let mut areas: Vec<f64> = fragments.iter().map(|f| polygon_area(f)).collect();
+        areas.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        assert_eq!(areas, vec![1.0, 1.0, 1.0, 8.0]);
+
+        assert_eq!(
+            round_area(
+                get_largest_polygon_area(&polygon_points, &[line]).unwrap(),
+                6
+            ),
+            8.0
+        );
+    }
+
+    #[test]
+    fn concave_segment_cuts_only_spans_it_covers() {
+        let polygon_points = vec![
+            Point::new(0.0, 0.0),
+            Point::new(3.0, 0.0),
+            Point::new(3.0, 1.0),
+            Point::new(1.0, 1.0),
+            Point::new(1.0, 2.0),
+            Point::new(3.0, 2.0),
+            Point::new(3.0, 3.0),
+            Point::new(1.0, 3.0),
+            Point::new(1.0, 4.0),
+            Point::new(3.0, 4.0),
+            Point::new(3.0, 5.0),
+            Point::new(0.0, 5.0),
+        ];
+        // Ends inside the middle prong, so only the bottom prong is cut off.
+        let line = Line::from_coords(2.0, -1.0, 2.0, 2.5);
+
+        let fragments = split_polygon(&polygon_points, &line).unwrap();
+        let mut areas: Vec<f64> = fragments.iter().map(|f| polygon_area(f)).collect();
+        areas.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        assert_eq!(areas, vec![1.0, 10.0]);
+    }
+
+    #[test]
+    fn concave_plus_shape() {
+        let polygon_points = vec![
+            Point::new(1.0, 0.0),
+            Point::new(2.0, 0.0),
+            Point::new(2.0, 1.0),
+            Point::new(3.0, 1.0),
+            Point::new(3.0, 2.0),
+            Point::new(2.0, 2.0),
+            Point::new(2.0, 3.0),
+            Point::new(1.0, 3.0),
+            Point::new(1.0, 2.0),
+            Point::new(0.0, 2.0),
+            Point::new(0.0, 1.0),
+            Point::new(1.0, 1.0),
+        ];
+        // Slices the top and bottom arms off just beside the centre square.
+        let lines = vec![
+            Line::from_coords(-1.0, 0.5, 4.0, 0.5),
+            Line::from_coords(-1.0, 2.5, 4.0, 2.5),
+        ];
+
+        assert_eq!(
+            round_area(
+                get_largest_polygon_area(&polygon_points, &lines).unwrap(),
+                6
+            ),
+            4.0
+        );
+    }
+
+    #[test]
+    fn cut_through_vertex_with_inexact_intersections() {
+        // The line passes through the vertex (0.3, 0.7). The intersection
+        // computed from each of its two edges differs in the last bits.
+        let polygon_points = vec![
+            Point::new(0.1, 0.1),
+            Point::new(0.9, 0.2),
+            Point::new(0.3, 0.7),
+            Point::new(0.0, 0.4),
+        ];
+        let line = Line::from_coords(0.1, 1.1, 0.7, -0.1);
+
+        let edge_a = Line::new(polygon_points[1], polygon_points[2]);
+        let edge_b = Line::new(polygon_points[2], polygon_points[3]);
+        let a = line_segment_intersection(&line, &edge_a).unwrap();
+        let b = line_segment_intersection(&line, &edge_b).unwrap();
+        assert_ne!(a, b);
+        assert!(points_equal(&a, &b));
+        assert_eq!(find_intersections(&polygon_points, &line).len(), 2);
+
+        let fragments = split_polygon(&polygon_points, &line).unwrap();
+        assert_eq!(fragments.len(), 2);
+        let total: f64 = fragments.iter().map(|f| polygon_area(f)).sum();
+        assert_eq!(
+            round_area(total, 6),
+            round_area(polygon_area(&polygon_points), 6)
+        );
+    }
+
+    #[test]
+    fn cut_through_corner_and_opposite_edge() {
+        let square = vec![
+            Point::new(0.0, 0.0),
+            Point::new(2.0, 0.0),
+            Point::new(2.0, 2.0),
+            Point::new(0.0, 2.0),
+        ];
+        // Enters through the corner (0, 0) and leaves through the top edge.
+        // Both edges at the corner meet the line, the crossing counts once.
+        let line = Line::from_coords(-0.5, -1.0, 1.5, 3.0);
+        assert_eq!(edges_crossed(&square, &line), 3);
+        assert_eq!(
+            find_intersections(&square, &line),
+            vec![Point::new(0.0, 0.0), Point::new(1.0, 2.0)]
+        );
+
+        let expected = vec![
+            vec![
+                Point::new(0.0, 0.0),
+                Point::new(2.0, 0.0),
+                Point::new(2.0, 2.0),
+                Point::new(1.0, 2.0),
+            ],
+            vec![
+                Point::new(1.0, 2.0),
+                Point::new(0.0, 2.0),
+                Point::new(0.0, 0.0),
+            ],
+        ];
+        // The corner ends up once in each piece, whichever vertex they start at.
+        let matches_expected = |fragments: Vec<Vec<Point>>| {
+            fragments.len() == 2
+                && fragments
+                    .iter()
+                    .zip(&expected)
+                    .all(|(fragment, expected)| polygon_eq(fragment, expected, EPSILON))
+        };
+        assert!(matches_expected(split_polygon(&square, &line).unwrap()));
+
+        // The same cut starting exactly on the corner.
+        let from_corner = Line::from_coords(0.0, 0.0, 1.5, 3.0);
+        assert!(matches_expected(
+            split_polygon(&square, &from_corner).unwrap()
+        ));
+    }
+
+    #[test]
+    fn custom_epsilon_for_large_coordinates() {
+        // Rectangle with coordinates in the millions, cut along its diagonal.
+        let (x, y) = (1234567.1, 7654321.3);
+        let polygon_points = vec![
+            Point::new(x, y),
+            Point::new(x + 1000.3, y),
+            Point::new(x + 1000.3, y + 1000.7),
+            Point::new(x, y + 1000.7),
+        ];
+        let line = Line::from_coords(x - 500.15, y - 500.35, x + 1500.45, y + 1501.05);
+        let options = SliceOptions {
+            epsilon: 1e-4,
+            ..SliceOptions::default()
+        };
+
+        let intersections = find_intersections_with_options(&polygon_points, &line, &options);
+        assert_eq!(intersections.len(), 2);
+
+        let fragments = split_polygon_with_options(&polygon_points, &line, &options).unwrap();
+        assert_eq!(fragments.len(), 2);
+        assert!(fragments.iter().all(|f| f.len() == 3));
+    }
+
+    #[test]
+    fn perimeter() {
+        let square = vec![
+            Point::new(0.0, 0.0),
+            Point::new(1.0, 0.0),
+            Point::new(1.0, 1.0),
+            Point::new(0.0, 1.0),
+        ];
+        assert_eq!(polygon_perimeter(&square), 4.0);
+
+        let segment = vec![Point::new(0.0, 0.0), Point::new(3.0, 4.0)];
+        assert_eq!(polygon_perimeter(&segment), 10.0);
+
+        assert_eq!(polygon_perimeter(&[Point::new(1.0, 1.0)]), 0.0);
+    }
+
+    #[test]
+    fn centroid() {
+        let square = vec![
+            Point::new(0.0, 0.0),
+            Point::new(1.0, 0.0),
+            Point::new(1.0, 1.0),
+            Point::new(0.0, 1.0),
+        ];
+        assert_eq!(polygon_centroid(&square), Some(Point::new(0.5, 0.5)));
+
+        let mut triangle = vec![
+            Point::new(0.0, 0.0),
+            Point::new(1.0, 0.0),
+            Point::new(0.0, 1.0),
+        ];
+        let centroid = polygon_centroid(&triangle).unwrap();
+        assert!(points_equal(&centroid, &Point::new(1.0 / 3.0, 1.0 / 3.0)));
+
+        triangle.reverse();
+        let centroid = polygon_centroid(&triangle).unwrap();
+        assert!(points_equal(&centroid, &Point::new(1.0 / 3.0, 1.0 / 3.0)));
+
+        let collinear = vec![
+            Point::new(0.0, 0.0),
+            Point::new(1.0, 1.0),
+            Point::new(2.0, 2.0),
+        ];
+        assert_eq!(polygon_centroid(&collinear), None);
+    }
+
+    #[test]
+    fn point_in_polygon_concave() {
+        // "U" shape opening upwards.
+        let polygon_points = vec![
+            Point::new(0.0, 0.0),
+            Point::new(3.0, 0.0),
+            Point::new(3.0, 3.0),
+            Point::new(2.0, 3.0),
+            Point::new(2.0, 1.0),
+            Point::new(1.0, 1.0),
+            Point::new(1.0, 3.0),
+            Point::new(0.0, 3.0),
+        ];
+
+        assert!(point_in_polygon(&polygon_points, &Point::new(0.5, 2.0)));
+        assert!(point_in_polygon(&polygon_points, &Point::new(1.5, 0.5)));
+        assert!(!point_in_polygon(&polygon_points, &Point::new(1.5, 2.0)));
+        assert!(!point_in_polygon(&polygon_points, &Point::new(4.0, 1.0)));
+        // A ray through a vertex must not be counted twice.
+        assert!(!point_in_polygon(&polygon_points, &Point::new(-1.0, 1.0)));
+
+        // Boundary counts as inside.
+        assert!(point_in_polygon(&polygon_points, &Point::new(2.0, 3.0)));
+        assert!(point_in_polygon(&polygon_points, &Point::new(1.5, 1.0)));
+    }
+
+    #[test]
+    fn largest_polygon_geometry() {
+        let polygon_points = vec![
+            Point::new(0.0, 0.0),
+            Point::new(1.0, 0.0),
+            Point::new(1.0, 1.0),
+            Point::new(0.0, 1.0),
+        ];
+        let lines = vec![Line::from_coords(0.25, 0.0, 0.25, 1.0)];
+
+        let largest = get_largest_polygon(&polygon_points, &lines).unwrap();
+        assert_eq!(largest.area, 0.75);
+        assert_eq!(largest.points.len(), 4);
+        assert!(largest.points.iter().all(|p| p.x >= 0.25));
+
+        let uncut = get_largest_polygon(&polygon_points, &[]).unwrap();
+        assert_eq!(uncut.points, polygon_points);
+        assert_eq!(uncut.area, 1.0);
+    }
+
+    #[test]
+    fn all_fragments() {
+        let polygon_points = vec![
+            Point::new(0.0, 0.0),
+            Point::new(1.0, 0.0),
+            Point::new(1.0, 1.0),
+            Point::new(0.0, 1.0),
+        ];
+        let lines = vec![
+            Line::from_coords(0.5, 0.0, 0.5, 1.0),
+            Line::from_coords(0.0, 0.5, 1.0, 0.5),
+            Line::from_coords(2.0, 2.0, 3.0, 3.0),
+        ];
+
+        let fragments = get_all_fragments(&polygon_points, &lines).unwrap();
+        assert_eq!(fragments.len(), 4);
+        assert!(fragments.iter().all(|f| polygon_area(f) == 0.25));
+
+        assert_eq!(
+            get_all_fragments(&polygon_points, &[]).unwrap(),
+            vec![polygon_points]
+        );
+    }
+
+    #[test]
+    fn smallest_and_all_areas() {
+        let polygon_points = vec![
+            Point::new(0.0, 0.0),
+            Point::new(1.0, 0.0),
+            Point::new(1.0, 1.0),
+            Point::new(0.0, 1.0),
+        ];
+        let lines = vec![
+            Line::from_coords(0.25, 0.0, 0.25, 1.0),
+            Line::from_coords(0.0, 0.5, 1.0, 0.5),
+        ];
+
+        let mut areas = get_all_areas(&polygon_points, &lines).unwrap();
+        assert_eq!(areas.len(), 4);
+        assert_eq!(round_area(areas.iter().sum::<f64>(), 6), 1.0);
+        areas.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        assert_eq!(areas, vec![0.125, 0.125, 0.375, 0.375]);
+
+        assert_eq!(
+            get_smallest_polygon_area(&polygon_points, &lines).unwrap(),
+            0.125
+        );
+        assert_eq!(
+            get_smallest_polygon_area(&polygon_points, &[]).unwrap(),
+            1.0
+        );
+    }
+
+    fn has_repeated_points(polygon: &[Point]) -> bool {
+        (0..polygon.len())
+            .any(|i| (i + 1..polygon.len()).any(|j| points_equal(&polygon[i], &polygon[j])))
+    }
+
+    /// Area as the sum of the ear clipped triangles, independent of the shoelace sum.
+    fn area_via_triangulation(polygon: &[Point]) -> f64 {
+        triangulate(polygon)
+            .iter()
+            .map(|[a, b, c]| (*b - *a).cross(&(*c - *a)).abs() / 2.0)
+            .sum()
+    }
+
+    #[test]
+    fn split_through_two_vertices() {
+        let polygon_points = vec![
+            Point::new(0.0, 0.0),
+            Point::new(1.0, 0.0),
+            Point::new(1.0, 1.0),
+            Point::new(0.0, 1.0),
+        ];
+        let line = Line::from_coords(0.0, 0.0, 1.0, 1.0);
+
+        let fragments = split_polygon(&polygon_points, &line).unwrap();
+        assert_eq!(fragments.len(), 2);
+        for fragment in &fragments {
+            assert_eq!(fragment.len(), 3);
+            assert!(!has_repeated_points(fragment));
+            assert_eq!(polygon_area(fragment), 0.5);
+        }
+    }
+
+    #[test]
+    fn split_through_one_vertex_and_an_edge() {
+        let polygon_points = vec![
+            Point::new(0.0, 0.0),
+            Point::new(2.0, 0.0),
+            Point::new(2.0, 2.0),
+            Point::new(1.0, 3.0),
+            Point::new(0.0, 2.0),
+        ];
+        let line = Line::from_coords(1.0, 4.0, 1.0, -1.0);
+
+        let fragments = split_polygon(&polygon_points, &line).unwrap();
+        assert_eq!(fragments.len(), 2);
+        for fragment in &fragments {
+            assert_eq!(fragment.len(), 4);
+            assert!(!has_repeated_points(fragment));
+            assert_eq!(polygon_area(fragment), 2.5);
+        }
+    }
+
+    #[test]
+    fn split_touching_vertex_only() {
+        let polygon_points = vec![
+            Point::new(0.0, 0.0),
+            Point::new(2.0, 0.0),
+            Point::new(2.0, 2.0),
+            Point::new(1.0, 3.0),
+            Point::new(0.0, 2.0),
+        ];
+        let line = Line::from_coords(-1.0, 3.0, 3.0, 3.0);
+
+        assert_eq!(
+            split_polygon(&polygon_points, &line),
+            Err(SliceError::NoIntersection)
+        );
+    }
+
+    #[test]
+    fn split_through_touching_vertices() {
+        // Two reflex tips on the line, with the peak between them above it.
+        let crown = vec![
+            Point::new(0.0, 0.0),
+            Point::new(4.0, 0.0),
+            Point::new(4.0, 2.0),
+            Point::new(3.0, 1.0),
+            Point::new(2.0, 2.0),
+            Point::new(1.0, 1.0),
+            Point::new(0.0, 2.0),
+        ];
+        // Two notch floors on the line.
+        let comb = vec![
+            Point::new(0.0, 0.0),
+            Point::new(5.0, 0.0),
+            Point::new(5.0, 3.0),
+            Point::new(4.0, 3.0),
+            Point::new(4.0, 1.0),
+            Point::new(3.0, 1.0),
+            Point::new(3.0, 3.0),
+            Point::new(2.0, 3.0),
+            Point::new(2.0, 1.0),
+            Point::new(1.0, 1.0),
+            Point::new(1.0, 3.0),
+            Point::new(0.0, 3.0),
+        ];
+        let line = Line::from_coords(-1.0, 1.0, 6.0, 1.0);
+        let backwards = Line::new(line.p2, line.p1);
+
+        for (mut polygon, pieces) in [(crown, 4), (comb, 4)] {
+            for _ in 0..2 {
+                polygon.reverse();
+                for line in [&line, &backwards] {
+                    let fragments = split_polygon(&polygon, line).unwrap();
+                    assert_eq!(fragments.len(), pieces);
+                    assert!(fragments.iter().all(|fragment| is_simple(fragment)));
+                    let total: f64 = fragments.iter().map(|f| polygon_area(f)).sum();
+                    assert!((total - polygon_area(&polygon)).abs() < 1e-9);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn split_at_notch_touching_the_line() {
+        // The tip of the notch splits the top in two.
+        let m_shape = vec![
+            Point::new(0.0, 0.0),
+            Point::new(2.0, 0.0),
+            Point::new(2.0, 2.0),
+            Point::new(1.0, 0.5),
+            Point::new(0.0, 2.0),
+        ];
+        let line = Line::from_coords(-1.0, 0.5, 3.0, 0.5);
+        assert_eq!(
+            get_largest_polygon_area(&m_shape, std::slice::from_ref(&line)),
+            Ok(1.0)
+        );
+        assert_eq!(fragment_count(&m_shape, std::slice::from_ref(&line)), Ok(3));
+        let mut areas = get_all_areas(&m_shape, std::slice::from_ref(&line)).unwrap();
+        areas.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        assert_eq!(areas, vec![0.75, 0.75, 1.0]);
+
+        // So does the floor of a notch lying on the line.
+        let u_shape = vec![
+            Point::new(0.0, 0.0),
+            Point::new(3.0, 0.0),
+            Point::new(3.0, 2.0),
+            Point::new(2.0, 2.0),
+            Point::new(2.0, 1.0),
+            Point::new(1.0, 1.0),
+            Point::new(1.0, 2.0),
+            Point::new(0.0, 2.0),
+        ];
+        let line = Line::from_coords(-1.0, 1.0, 4.0, 1.0);
+        let fragments = split_polygon(&u_shape, &line).unwrap();
+        assert_eq!(fragments.len(), 3);
+        assert!(fragments.iter().all(|fragment| is_simple(fragment)));
+        let mut areas: Vec<f64> = fragments.iter().map(|f| polygon_area(f)).collect();
+        areas.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        assert_eq!(areas, vec![1.0, 1.0, 3.0]);
+
+        // Cutting again through where the pieces meet leaves them as they are.
+        let lines = vec![line, Line::from_coords(1.0, -1.0, 1.0, 3.0)];
+        let fragments = get_all_fragments(&u_shape, &lines).unwrap();
+        assert_eq!(fragments.len(), 4);
+        assert!(fragments.iter().all(|fragment| is_simple(fragment)));
+    }
+
+    #[test]
+    fn polygon_with_holes_net_area() {
+        let exterior = vec![
+            Point::new(0.0, 0.0),
+            Point::new(4.0, 0.0),
+            Point::new(4.0, 4.0),
+            Point::new(0.0, 4.0),
+        ];
+        let hole = vec![
+            Point::new(1.0, 1.0),
+            Point::new(1.0, 3.0),
+            Point::new(3.0, 3.0),
+            Point::new(3.0, 1.0),
+        ];
+        let washer = Polygon::with_holes(exterior.clone(), vec![hole.clone()]);
+        assert_eq!(washer.net_area(), 12.0);
+
+        // Same winding as the exterior gives the same result.
+        let mut same_winding = hole;
+        same_winding.reverse();
+        let washer = Polygon::with_holes(exterior.clone(), vec![same_winding]);
+        assert_eq!(washer.net_area(), 12.0);
+
+        assert_eq!(Polygon::new(exterior).net_area(), 16.0);
+    }
+
+    #[test]
+    fn sample_in_f32() {
+        let polygon_points: Vec<PointF32> = vec![
+            Point::new(0.0, 0.0),
+            Point::new(1.0, 0.0),
+            Point::new(1.0, 1.0),
+            Point::new(0.0, 1.0),
+        ];
+        let lines: Vec<LineF32> = vec![
+            Line::from_coords(0.0, 0.0, 1.0, 1.0),
+            Line::from_coords(0.5, 0.0, 0.5, 1.0),
+        ];
+
+        let area = get_largest_polygon_area(&polygon_points, &lines).unwrap();
+        assert!((area - 0.375).abs() < 1e-6);
+    }
+
+    #[test]
+    fn display() {
+        let point = Point::new(0.5, 1.0);
+        assert_eq!(point.to_string(), "(0.5, 1)");
+        assert_eq!(format!("{:.3}", point), "(0.500, 1.000)");
+
+        let line = Line::from_coords(0.5, 1.0, 0.26, 2.0);
+        assert_eq!(line.to_string(), "(0.5, 1) -> (0.26, 2)");
+        assert_eq!(format!("{:.1}", line), "(0.5, 1.0) -> (0.3, 2.0)");
+    }
+
+    #[test]
+    fn point_arithmetic() {
+        let a = Point::new(0.1, 0.7);
+        let b = Point::new(0.3, -0.2);
+        assert!(points_equal(&(a - b + b), &a));
+
+        assert_eq!(a + b, Point::new(0.1 + 0.3, 0.7 - 0.2));
+        assert_eq!(Point::new(1.0, 2.0) * 3.0, Point::new(3.0, 6.0));
+        assert_eq!(Point::new(3.0, 6.0) / 3.0, Point::new(1.0, 2.0));
+
+        let x_axis = Point::new(1.0, 0.0);
+        let y_axis = Point::new(0.0, 1.0);
+        assert_eq!(x_axis.dot(&y_axis), 0.0);
+        assert_eq!(Point::new(2.0, 3.0).dot(&Point::new(4.0, 5.0)), 23.0);
+        assert_eq!(x_axis.cross(&y_axis), 1.0);
+        assert_eq!(y_axis.cross(&x_axis), -1.0);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip() {
+        let point = Point::new(0.5, 1.0);
+        assert_eq!(
+            serde_json::to_string(&point).unwrap(),
+            r#"{"x":0.5,"y":1.0}"#
+        );
+
+        let polygon = Polygon::with_holes(
+            vec![
+                Point::new(0.0, 0.0),
+                Point::new(4.0, 0.0),
+                Point::new(4.0, 4.0),
+                Point::new(0.0, 4.0),
+            ],
+            vec![vec![
+                Point::new(1.0, 1.0),
+                Point::new(1.0, 3.0),
+                Point::new(3.0, 3.0),
+            ]],
+        );
+        let json = serde_json::to_string(&polygon).unwrap();
+        let parsed: Polygon = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, polygon);
+
+        let line = Line::from_coords(0.0, 0.5, 1.0, 0.5);
+        let json = serde_json::to_string(&line).unwrap();
+        let parsed: Line = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.p1, line.p1);
+        assert_eq!(parsed.p2, line.p2);
+    }
+
+    #[test]
+    fn simple_polygons() {
+        let bowtie = vec![
+            Point::new(0.0, 0.0),
+            Point::new(1.0, 1.0),
+            Point::new(1.0, 0.0),
+            Point::new(0.0, 1.0),
+        ];
+        assert!(!is_simple(&bowtie));
+
+        let square = vec![
+            Point::new(0.0, 0.0),
+            Point::new(1.0, 0.0),
+            Point::new(1.0, 1.0),
+            Point::new(0.0, 1.0),
+        ];
+        assert!(is_simple(&square));
+
+        let triangle = vec![
+            Point::new(0.0, 0.0),
+            Point::new(1.0, 0.0),
+            Point::new(0.0, 1.0),
+        ];
+        assert!(is_simple(&triangle));
+
+        // Concave, with a vertex touching the opposite edge.
+        let pinched = vec![
+            Point::new(0.0, 0.0),
+            Point::new(2.0, 0.0),
+            Point::new(2.0, 2.0),
+            Point::new(1.0, 0.0),
+            Point::new(0.0, 2.0),
+        ];
+        assert!(!is_simple(&pinched));
+
+        let u_shape = vec![
+            Point::new(0.0, 0.0),
+            Point::new(3.0, 0.0),
+            Point::new(3.0, 3.0),
+            Point::new(2.0, 3.0),
+            Point::new(2.0, 1.0),
+            Point::new(1.0, 1.0),
+            Point::new(1.0, 3.0),
+            Point::new(0.0, 3.0),
+        ];
+        assert!(is_simple(&u_shape));
+    }
+
+    #[test]
+    fn bounding_box_of_rotated_triangle() {
+        let triangle = vec![
+            Point::new(0.5, -1.0),
+            Point::new(2.0, 1.5),
+            Point::new(-0.75, 0.25),
+        ];
+
+        let (min, max) = bounding_box(&triangle).unwrap();
+        assert_eq!(min, Point::new(-0.75, -1.0));
+        assert_eq!(max, Point::new(2.0, 1.5));
+        for point in &triangle {
+            assert!(point.x >= min.x && point.x <= max.x);
+            assert!(point.y >= min.y && point.y <= max.y);
+        }
+
+        assert_eq!(bounding_box::<f64>(&[]), None);
+    }
+
+    #[test]
+    fn segment_intersection_kinds() {
+        let bottom = Line::from_coords(0.0, 0.0, 1.0, 0.0);
+
+        assert_eq!(
+            segment_intersection(&bottom, &Line::from_coords(0.5, -1.0, 0.5, 1.0)),
+            Intersection::Point(Point::new(0.5, 0.0))
+        );
+        assert_eq!(
+            segment_intersection(&bottom, &Line::from_coords(2.0, -1.0, 2.0, 1.0)),
+            Intersection::None
+        );
+
+        // Parallel but on another line.
+        assert_eq!(
+            segment_intersection(&bottom, &Line::from_coords(0.0, 1.0, 1.0, 1.0)),
+            Intersection::None
+        );
+
+        // Collinear, overlapping and clamped to the first segment.
+        assert_eq!(
+            segment_intersection(&bottom, &Line::from_coords(2.0, 0.0, 0.25, 0.0)),
+            Intersection::Collinear(Point::new(0.25, 0.0), Point::new(1.0, 0.0))
+        );
+        assert_eq!(
+            line_segment_intersection(&bottom, &Line::from_coords(2.0, 0.0, 0.25, 0.0)),
+            None
+        );
+
+        // Collinear, only touching at an end point.
+        assert_eq!(
+            segment_intersection(&bottom, &Line::from_coords(1.0, 0.0, 2.0, 0.0)),
+            Intersection::Point(Point::new(1.0, 0.0))
+        );
+
+        // Collinear with a gap in between.
+        assert_eq!(
+            segment_intersection(&bottom, &Line::from_coords(1.5, 0.0, 2.0, 0.0)),
+            Intersection::None
+        );
+    }
+
+    #[test]
+    fn cut_along_an_edge() {
+        let square = vec![
+            Point::new(0.0, 0.0),
+            Point::new(1.0, 0.0),
+            Point::new(1.0, 1.0),
+            Point::new(0.0, 1.0),
+        ];
+        let along_bottom = Line::from_coords(-1.0, 0.0, 2.0, 0.0);
+
+        assert_eq!(
+            split_polygon(&square, &along_bottom),
+            Err(SliceError::NoIntersection)
+        );
+        assert_eq!(
+            get_largest_polygon_area(&square, &[along_bottom]).unwrap(),
+            1.0
+        );
+    }
+
+    #[test]
+    fn cut_along_an_inner_edge() {
+        // Both windings of an "L", so the run along the line is found
+        // on either side of the span cutting through the polygon.
+        let l_shape = vec![
+            Point::new(0.0, 0.0),
+            Point::new(2.0, 0.0),
+            Point::new(2.0, 1.0),
+            Point::new(1.0, 1.0),
+            Point::new(1.0, 2.0),
+            Point::new(0.0, 2.0),
+        ];
+        let mirrored_l_shape = vec![
+            Point::new(0.0, 0.0),
+            Point::new(2.0, 0.0),
+            Point::new(2.0, 2.0),
+            Point::new(1.0, 2.0),
+            Point::new(1.0, 1.0),
+            Point::new(0.0, 1.0),
+        ];
+        let line = Line::from_coords(-1.0, 1.0, 3.0, 1.0);
+
+        for polygon_points in [l_shape, mirrored_l_shape] {
+            let fragments = split_polygon(&polygon_points, &line).unwrap();
+            assert_eq!(fragments.len(), 2);
+
+            let mut areas: Vec<f64> = fragments.iter().map(|f| polygon_area(f)).collect();
+            areas.sort_by(|a, b| a.partial_cmp(b).unwrap());
+            assert_eq!(areas, vec![1.0, 2.0]);
+
+            // No zero area spikes running back along the edge.
+            for fragment in &fragments {
+                assert!(!has_repeated_points(fragment));
+                let expected = if polygon_area(fragment) == 1.0 {
+                    4.0
+                } else {
+                    6.0
+                };
+                assert_eq!(polygon_perimeter(fragment), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn point_distance() {
+        let a = Point::new(1.0, 1.0);
+        let b = Point::new(4.0, 5.0);
+        assert_eq!(a.distance(&b), 5.0);
+        assert_eq!(a.distance_squared(&b), 25.0);
+        assert_eq!(b.distance(&a), 5.0);
+        assert_eq!(a.distance(&a), 0.0);
+    }
+
+    #[test]
+    fn line_helpers() {
+        let diagonal = Line::from_coords(0.0, 0.0, 1.0, 1.0);
+        assert_eq!(diagonal.midpoint(), Point::new(0.5, 0.5));
+        assert_eq!(diagonal.point_at(0.25), Point::new(0.25, 0.25));
+        assert_eq!(diagonal.point_at(0.0), diagonal.p1);
+        assert_eq!(diagonal.point_at(1.0), diagonal.p2);
+        assert_eq!(diagonal.point_at(2.0), Point::new(2.0, 2.0));
+        assert_eq!(diagonal.length(), 2.0f64.sqrt());
+
+        assert_eq!(Line::from_coords(1.0, 1.0, 4.0, 5.0).length(), 5.0);
+    }
+
+    #[test]
+    fn signed_area_follows_winding() {
+        let ccw = vec![
+            Point::new(0.0, 0.0),
+            Point::new(2.0, 0.0),
+            Point::new(2.0, 1.0),
+            Point::new(0.0, 1.0),
+        ];
+        let cw: Vec<Point> = ccw.iter().rev().copied().collect();
+
+        assert_eq!(polygon_signed_area(&ccw), 2.0);
+        assert_eq!(polygon_signed_area(&cw), -2.0);
+        assert_eq!(polygon_area(&cw), 2.0);
+
+        // No rounding unless asked for.
+        let thin = vec![
+            Point::new(0.0, 0.0),
+            Point::new(1.0, 0.0),
+            Point::new(1.0, 1e-9),
+        ];
+        assert_eq!(polygon_area(&thin), 5e-10);
+        assert_eq!(round_to_decimals(polygon_area(&thin), 7), 0.0);
+        assert_eq!(round_to_decimals(0.123456789_f64, 3), 0.123);
+    }
+
+    #[test]
+    fn winding_normalization() {
+        let mut square = vec![
+            Point::new(0.0, 0.0),
+            Point::new(0.0, 1.0),
+            Point::new(1.0, 1.0),
+            Point::new(1.0, 0.0),
+        ];
+        assert!(!is_ccw(&square));
+
+        ensure_ccw(&mut square);
+        assert!(is_ccw(&square));
+        assert_eq!(
+            square,
+            vec![
+                Point::new(1.0, 0.0),
+                Point::new(1.0, 1.0),
+                Point::new(0.0, 1.0),
+                Point::new(0.0, 0.0),
+            ]
+        );
+
+        // Already counter-clockwise input is left alone.
+        let before = square.clone();
+        ensure_ccw(&mut square);
+        assert_eq!(square, before);
+    }
+
+    #[test]
+    fn intersections_ordered_along_diagonal_cut() {
+        // U shape with the opening at the top.
+        let polygon_points = vec![
+            Point::new(0.0, 0.0),
+            Point::new(3.0, 0.0),
+            Point::new(3.0, 3.0),
+            Point::new(2.0, 3.0),
+            Point::new(2.0, 1.0),
+            Point::new(1.0, 1.0),
+            Point::new(1.0, 3.0),
+            Point::new(0.0, 3.0),
+        ];
+        // Runs right to left, so the order along the line is the
+        // opposite of sorting by x.
+        let line = Line::from_coords(3.5, 2.75, -0.5, 1.75);
+
+        assert_eq!(
+            find_intersections(&polygon_points, &line),
+            vec![
+                Point::new(3.0, 2.625),
+                Point::new(2.0, 2.375),
+                Point::new(1.0, 2.125),
+                Point::new(0.0, 1.875),
+            ]
+        );
+
+        let fragments = get_all_fragments(&polygon_points, &[line]).unwrap();
+        assert_eq!(fragments.len(), 3);
+        let total: f64 = fragments.iter().map(|f| polygon_area(f)).sum();
+        assert_eq!(round_area(total, 6), polygon_area(&polygon_points));
+    }
+
+    #[test]
+    fn slivers_removed() {
+        let polygon_points = vec![
+            Point::new(0.0, 0.0),
+            Point::new(1.0, 0.0),
+            Point::new(1.0, 1.0),
+            Point::new(0.0, 1.0),
+        ];
+        // Passes just inside the top right corner, outside the tolerance.
+        let line = Line::from_coords(0.5, 1.5 - 1e-8, 1.5, 0.5 - 1e-8);
+
+        let fragments = get_all_fragments(&polygon_points, &[line]).unwrap();
+        assert_eq!(fragments.len(), 2);
+        assert!(fragments.iter().any(|f| polygon_area(f) < 1e-15));
+
+        let fragments = remove_slivers(fragments, 1e-9);
+        assert_eq!(fragments.len(), 1);
+        assert_eq!(round_area(polygon_area(&fragments[0]), 6), 1.0);
+    }
+
+    #[test]
+    fn triangulation_covers_polygon() {
+        // Concave "E" like shape with a collinear vertex on the bottom edge.
+        let polygon_points = vec![
+            Point::new(0.0, 0.0),
+            Point::new(1.5, 0.0),
+            Point::new(3.0, 0.0),
+            Point::new(3.0, 1.0),
+            Point::new(1.0, 1.0),
+            Point::new(1.0, 2.0),
+            Point::new(3.0, 2.0),
+            Point::new(3.0, 3.0),
+            Point::new(0.0, 3.0),
+        ];
+        let triangles = triangulate(&polygon_points);
+        assert_eq!(triangles.len(), polygon_points.len() - 3);
+        let total: f64 = triangles.iter().map(|t| polygon_signed_area(t)).sum();
+        assert!((total - polygon_area(&polygon_points)).abs() < EPSILON);
+
+        // Clockwise input gives the same area.
+        let clockwise: Vec<Point> = polygon_points.iter().rev().copied().collect();
+        let total: f64 = triangulate(&clockwise)
+            .iter()
+            .map(|t| polygon_signed_area(t))
+            .sum();
+        assert!((total - polygon_area(&polygon_points)).abs() < EPSILON);
+
+        assert!(triangulate(&polygon_points[..2]).is_empty());
+    }
+
+    #[test]
+    fn convex_hull_excludes_interior_points() {
+        let points = vec![
+            Point::new(1.0, 1.0),
+            Point::new(0.0, 0.0),
+            Point::new(2.0, 2.0),
+            Point::new(0.5, 1.5),
+            Point::new(2.0, 0.0),
+            Point::new(1.0, 0.0),
+            Point::new(0.0, 2.0),
+            Point::new(2.0, 0.0),
+        ];
+        assert_eq!(
+            convex_hull(&points),
+            vec![
+                Point::new(0.0, 0.0),
+                Point::new(2.0, 0.0),
+                Point::new(2.0, 2.0),
+                Point::new(0.0, 2.0),
+            ]
+        );
+
+        let collinear = vec![
+            Point::new(1.0, 1.0),
+            Point::new(0.0, 0.0),
+            Point::new(2.0, 2.0),
+        ];
+        assert_eq!(
+            convex_hull(&collinear),
+            vec![Point::new(0.0, 0.0), Point::new(2.0, 2.0)]
+        );
+        assert_eq!(convex_hull(&collinear[..1]), vec![Point::new(1.0, 1.0)]);
+        assert!(convex_hull::<f64>(&[]).is_empty());
+    }
+
+    #[test]
+    fn convexity() {
+        let square = vec![
+            Point::new(0.0, 0.0),
+            Point::new(1.0, 0.0),
+            Point::new(1.0, 1.0),
+            Point::new(0.0, 1.0),
+        ];
+        assert!(is_convex(&square));
+        let clockwise: Vec<Point> = square.iter().rev().copied().collect();
+        assert!(is_convex(&clockwise));
+
+        // A vertex in the middle of an edge doesn't matter.
+        let mut with_midpoint = square.clone();
+        with_midpoint.insert(1, Point::new(0.5, 0.0));
+        assert!(is_convex(&with_midpoint));
+
+        let l_shape = vec![
+            Point::new(0.0, 0.0),
+            Point::new(2.0, 0.0),
+            Point::new(2.0, 1.0),
+            Point::new(1.0, 1.0),
+            Point::new(1.0, 2.0),
+            Point::new(0.0, 2.0),
+        ];
+        assert!(!is_convex(&l_shape));
+        assert!(!is_convex(&square[..2]));
+    }
+
+    #[test]
+    fn cut_modes() {
+        let polygon_points = vec![
+            Point::new(0.0, 0.0),
+            Point::new(2.0, 0.0),
+            Point::new(2.0, 2.0),
+            Point::new(0.0, 2.0),
+        ];
+        // Short line in the middle, reaching neither side of the square.
+        let line = Line::from_coords(0.9, 1.0, 1.1, 1.0);
+        let options = |cut_mode| SliceOptions {
+            cut_mode,
+            ..SliceOptions::default()
+        };
+
+        assert_eq!(
+            split_polygon(&polygon_points, &line),
+            Err(SliceError::NoIntersection)
+        );
+        assert!(find_intersections(&polygon_points, &line).is_empty());
+
+        let fragments =
+            split_polygon_with_options(&polygon_points, &line, &options(CutMode::Line)).unwrap();
+        assert_eq!(fragments.len(), 2);
+        assert!(fragments.iter().all(|f| polygon_area(f) == 2.0));
+        assert_eq!(
+            find_intersections_with_options(&polygon_points, &line, &options(CutMode::Line)),
+            vec![Point::new(0.0, 1.0), Point::new(2.0, 1.0)]
+        );
+
+        // The ray only reaches the right side.
+        assert!(
+            split_polygon_with_options(&polygon_points, &line, &options(CutMode::Ray)).is_err()
+        );
+        assert_eq!(
+            find_intersections_with_options(&polygon_points, &line, &options(CutMode::Ray)),
+            vec![Point::new(2.0, 1.0)]
+        );
+        let from_outside = Line::from_coords(-1.0, 1.0, -0.5, 1.0);
+        assert_eq!(
+            split_polygon_with_options(&polygon_points, &from_outside, &options(CutMode::Ray))
+                .unwrap()
+                .len(),
+            2
+        );
+    }
+
+    #[test]
+    fn halfplane_areas_sum_to_total() {
+        // U shape, the line crosses it four times.
+        let polygon_points: Vec<Point> = vec![
+            Point::new(0.0, 0.0),
+            Point::new(3.0, 0.0),
+            Point::new(3.0, 3.0),
+            Point::new(2.0, 3.0),
+            Point::new(2.0, 1.0),
+            Point::new(1.0, 1.0),
+            Point::new(1.0, 3.0),
+            Point::new(0.0, 3.0),
+        ];
+        let line = Line::from_coords(0.0, 2.0, 1.0, 2.0);
+
+        let (positive, negative) = halfplane_areas(&polygon_points, &line);
+        // Below the line is on the right of p1 -> p2, the positive side.
+        assert_eq!(positive, 5.0);
+        assert_eq!(negative, 2.0);
+        assert!((positive + negative - polygon_area(&polygon_points)).abs() < EPSILON);
+
+        let outside = Line::from_coords(5.0, 0.0, 5.0, 1.0);
+        assert_eq!(halfplane_areas(&polygon_points, &outside), (0.0, 7.0));
+    }
+
+    #[test]
+    fn clip_to_convex_polygon() {
+        let square = vec![
+            Point::new(0.0, 0.0),
+            Point::new(1.0, 0.0),
+            Point::new(1.0, 1.0),
+            Point::new(0.0, 1.0),
+        ];
+        let rectangle = vec![
+            Point::new(0.25, 0.25),
+            Point::new(0.75, 0.25),
+            Point::new(0.75, 0.75),
+            Point::new(0.25, 0.75),
+        ];
+
+        let clipped = clip_polygon(&square, &rectangle);
+        assert_eq!(clipped.len(), 4);
+        assert!(clipped.iter().all(|p| rectangle.contains(p)));
+        assert_eq!(polygon_area(&clipped), 0.25);
+
+        // Winding of the clip polygon doesn't matter.
+        let clockwise: Vec<Point> = rectangle.iter().rev().copied().collect();
+        assert_eq!(polygon_area(&clip_polygon(&square, &clockwise)), 0.25);
+
+        // Partly overlapping.
+        let shifted: Vec<Point> = square.iter().map(|p| *p + Point::new(0.5, 0.5)).collect();
+        assert_eq!(polygon_area(&clip_polygon(&square, &shifted)), 0.25);
+
+        let far_away: Vec<Point> = square.iter().map(|p| *p + Point::new(5.0, 0.0)).collect();
+        assert!(clip_polygon(&square, &far_away).is_empty());
+    }
+
+    #[test]
+    fn bisect_area_through_point() {
+        let square: Vec<Point> = vec![
+            Point::new(0.0, 0.0),
+            Point::new(1.0, 0.0),
+            Point::new(1.0, 1.0),
+            Point::new(0.0, 1.0),
+        ];
+        let line = area_bisector_through(&square, &Point::new(0.5, 0.5)).unwrap();
+        let halves = split_polygon(&square, &line).unwrap();
+        assert_eq!(halves.len(), 2);
+        assert!(halves.iter().all(|f| (polygon_area(f) - 0.5).abs() < 1e-9));
+
+        // Off-centre pivot in a concave shape.
+        let l_shape: Vec<Point> = vec![
+            Point::new(0.0, 0.0),
+            Point::new(2.0, 0.0),
+            Point::new(2.0, 1.0),
+            Point::new(1.0, 1.0),
+            Point::new(1.0, 2.0),
+            Point::new(0.0, 2.0),
+        ];
+        let line = area_bisector_through(&l_shape, &Point::new(0.25, 0.5)).unwrap();
+        let (positive, negative) = halfplane_areas(&l_shape, &line);
+        assert!((positive - 1.5).abs() < 1e-9);
+        assert!((negative - 1.5).abs() < 1e-9);
+
+        assert!(area_bisector_through(&square, &Point::new(2.0, 0.5)).is_none());
+    }
+
+    #[test]
+    fn areas_after_each_cut() {
+        let polygon_points = vec![
+            Point::new(0.0, 0.0),
+            Point::new(1.0, 0.0),
+            Point::new(1.0, 1.0),
+            Point::new(0.0, 1.0),
+        ];
+        let lines = vec![
+            Line::from_coords(0.0, 0.0, 1.0, 1.0),
+            Line::from_coords(0.5, 0.0, 0.5, 1.0),
+        ];
+
+        let steps = get_areas_per_cut(&polygon_points, &lines).unwrap();
+        assert_eq!(steps.len(), 2);
+        assert_eq!(steps[0], vec![0.5, 0.5]);
+        assert_eq!(steps[1], get_all_areas(&polygon_points, &lines).unwrap());
+        assert!(get_areas_per_cut(&polygon_points, &[]).unwrap().is_empty());
+    }
+
+    #[test]
+    fn area_of_large_offset_circle() {
+        // A plain running sum is off by about 3e-4 here.
+        let vertex_count = 100_000;
+        let radius = 100.0;
+        let polygon_points: Vec<Point> = (0..vertex_count)
+            .map(|i| {
+                let angle = i as f64 / vertex_count as f64 * std::f64::consts::TAU;
+                Point::new(1e5 + radius * angle.cos(), 1e5 + radius * angle.sin())
+            })
+            .collect();
+
+        let exact = vertex_count as f64 / 2.0
+            * radius
+            * radius
+            * (std::f64::consts::TAU / vertex_count as f64).sin();
+        assert!((polygon_area(&polygon_points) - exact).abs() < 1e-8);
+    }
+
+    #[test]
+    fn point_conversions() {
+        let p: Point = (0.5, 1.0).into();
+        assert_eq!(p, Point::new(0.5, 1.0));
+        assert_eq!(Point::from([0.5, 1.0]), p);
+        assert_eq!(<(f64, f64)>::from(p), (0.5, 1.0));
+
+        let square: Vec<Point> = vec![(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)]
+            .into_iter()
+            .map(Point::from)
+            .collect();
+        assert_eq!(polygon_area(&square), 1.0);
+    }
+
+    #[test]
+    fn polygon_edges() {
+        let square = vec![
+            Point::new(0.0, 0.0),
+            Point::new(1.0, 0.0),
+            Point::new(1.0, 1.0),
+            Point::new(0.0, 1.0),
+        ];
+        let square_edges: Vec<Line> = edges(&square).collect();
+        assert_eq!(square_edges.len(), 4);
+        assert_eq!(square_edges[0].p1, square[0]);
+        assert_eq!(square_edges[0].p2, square[1]);
+        // Closing edge back to the start.
+        assert_eq!(square_edges[3].p1, square[3]);
+        assert_eq!(square_edges[3].p2, square[0]);
+
+        assert_eq!(edges::<f64>(&[]).count(), 0);
+    }
+
+    #[test]
+    fn area_conserved_across_random_cuts() {
+        use proptest::prelude::*;
+        use proptest::test_runner::{Config, RngAlgorithm, TestRng, TestRunner};
+
+        use crate::testing::random_simple_polygon;
+
+        // Snapped to a coarse grid, vertices line up with each other and with
+        // the grid lines, which is where cuts through vertices go wrong.
+        const GRID: f64 = 0.25;
+        let polygon = (3usize..20, any::<u64>(), any::<bool>())
+            .prop_map(|(vertex_count, seed, snap)| {
+                let polygon = random_simple_polygon(vertex_count, seed);
+                if snap {
+                    clean_polygon(&snap_to_grid(&polygon, GRID), EPSILON)
+                } else {
+                    polygon
+                }
+            })
+            .prop_filter("snapping can make the polygon degenerate", |polygon| {
+                polygon.len() >= 3 && is_simple(polygon) && polygon_area(polygon) > 0.0
+            });
+        let cases = polygon.prop_flat_map(|polygon| {
+            let point = (-1.5..1.5, -1.5..1.5).prop_map(Point::from);
+            let vertices = polygon.clone();
+            let vertex = 0..polygon.len();
+            let line = prop_oneof![
+                (point.clone(), point).prop_map(|(p1, p2)| Line::new(p1, p2)),
+                (vertex.clone(), vertex)
+                    .prop_filter("two vertices", |(a, b)| a != b)
+                    .prop_map(move |(a, b)| Line::new(vertices[a], vertices[b])),
+                (-6i32..=6).prop_map(|row| {
+                    let y = row as f64 * GRID;
+                    Line::from_coords(-2.0, y, 2.0, y)
+                }),
+            ];
+            (Just(polygon), prop::collection::vec(line, 1..5))
+        });
+
+        let config = Config {
+            cases: 500,
+            failure_persistence: None,
+            ..Config::default()
+        };
+        let mut runner =
+            TestRunner::new_with_rng(config, TestRng::deterministic_rng(RngAlgorithm::ChaCha));
+        runner
+            .run(&cases, |(polygon_points, lines)| {
+                let fragments = get_all_fragments(&polygon_points, &lines).unwrap();
+                for fragment in &fragments {
+                    prop_assert!(is_simple(fragment), "fragment {:?} is not simple", fragment);
+                }
+
+                let total: f64 = fragments.iter().map(|f| polygon_area(f)).sum();
+                let expected = polygon_area(&polygon_points);
+                prop_assert!(
+                    (total - expected).abs() < 1e-9,
+                    "fragments add up to {} instead of {}",
+                    total,
+                    expected
+                );
+                Ok(())
+            })
+            .unwrap();
+    }
+
+    #[test]
+    fn split_errors() {
+        let square = vec![
+            Point::new(0.0, 0.0),
+            Point::new(1.0, 0.0),
+            Point::new(1.0, 1.0),
+            Point::new(0.0, 1.0),
+        ];
+        let line = Line::from_coords(0.5, -1.0, 0.5, 2.0);
+
+        assert_eq!(
+            split_polygon(&square[..2], &line),
+            Err(SliceError::NotEnoughVertices)
+        );
+        assert_eq!(
+            split_polygon(&square, &Line::from_coords(0.5, 0.5, 0.5, 0.5)),
+            Err(SliceError::DegenerateLine)
+        );
+        assert_eq!(
+            split_polygon(&square, &Line::from_coords(2.0, -1.0, 2.0, 2.0)),
+            Err(SliceError::NoIntersection)
+        );
+        assert!(split_polygon(&square, &line).is_ok());
+        assert_eq!(
+            SliceError::DegenerateLine.to_string(),
+            "cut line has zero length"
+        );
+    }
+
+    #[test]
+    fn zero_length_lines_never_intersect() {
+        let line = Line::from_coords(0.0, 0.0, 2.0, 0.0);
+        let on_line = Line::from_coords(1.0, 0.0, 1.0, 0.0);
+        let off_line = Line::from_coords(1.0, 1.0, 1.0, 1.0);
+
+        for degenerate in [&on_line, &off_line] {
+            assert_eq!(line_segment_intersection(&line, degenerate), None);
+            assert_eq!(line_segment_intersection(degenerate, &line), None);
+            assert_eq!(segment_intersection(&line, degenerate), Intersection::None);
+            assert_eq!(
+                segment_intersection(degenerate, degenerate),
+                Intersection::None
+            );
+        }
+    }
+
+    #[test]
+    fn collinear_vertices_removed() {
+        let square = vec![
+            Point::new(0.0, 0.0),
+            Point::new(0.5, 0.0),
+            Point::new(1.0, 0.0),
+            Point::new(1.0, 1.0),
+            Point::new(0.5, 1.0 + 1e-12),
+            Point::new(0.0, 1.0),
+        ];
+        assert_eq!(
+            remove_collinear_vertices(&square, EPSILON),
+            vec![
+                Point::new(0.0, 0.0),
+                Point::new(1.0, 0.0),
+                Point::new(1.0, 1.0),
+                Point::new(0.0, 1.0),
+            ]
+        );
+
+        // The start point itself can be the redundant one.
+        let rotated: Vec<Point> = square[1..].iter().chain(&square[..1]).copied().collect();
+        assert_eq!(remove_collinear_vertices(&rotated, EPSILON).len(), 4);
+
+        // Outside the tolerance the vertex is a real corner.
+        assert_eq!(remove_collinear_vertices(&square, 1e-13).len(), 5);
+    }
+
+    #[test]
+    fn rdp_collapses_staircase() {
+        // Bottom edge is a staircase rising 0.001 every 0.1.
+        let mut polygon_points = Vec::new();
+        for step in 0..10 {
+            let y = step as f64 * 0.001;
+            polygon_points.push(Point::new(step as f64 * 0.1, y));
+            polygon_points.push(Point::new((step + 1) as f64 * 0.1, y));
+        }
+        polygon_points.push(Point::new(1.0, 1.0));
+        polygon_points.push(Point::new(0.0, 1.0));
+
+        let simplified = simplify_rdp(&polygon_points, 0.01);
+        assert_eq!(
+            simplified,
+            vec![
+                Point::new(0.0, 0.0),
+                polygon_points[19],
+                Point::new(1.0, 1.0),
+                Point::new(0.0, 1.0),
+            ]
+        );
+        assert!(is_simple(&simplified));
+
+        // A small enough tolerance keeps every step.
+        assert_eq!(simplify_rdp(&polygon_points, 1e-4), polygon_points);
+    }
+
+    #[test]
+    fn clean_closed_ring() {
+        let ring = vec![
+            Point::new(0.0, 0.0),
+            Point::new(1.0, 0.0),
+            Point::new(1.0, 0.0),
+            Point::new(1.0, 1.0),
+            Point::new(0.0, 1.0),
+            Point::new(0.0, 0.0),
+        ];
+        let square = vec![
+            Point::new(0.0, 0.0),
+            Point::new(1.0, 0.0),
+            Point::new(1.0, 1.0),
+            Point::new(0.0, 1.0),
+        ];
+        assert_eq!(clean_polygon(&ring, EPSILON), square);
+        assert_eq!(clean_polygon(&square, EPSILON), square);
+
+        let fragments = split_polygon(&ring, &Line::from_coords(0.5, -1.0, 0.5, 2.0)).unwrap();
+        assert_eq!(fragments.len(), 2);
+        for fragment in &fragments {
+            assert!(!has_repeated_points(fragment));
+            assert_eq!(polygon_area(fragment), 0.5);
+        }
+    }
+
+    #[test]
+    fn explicitly_closed_ring() {
+        let ring = vec![
+            Point::new(0.0, 0.0),
+            Point::new(1.0, 0.0),
+            Point::new(1.0, 1.0),
+            Point::new(0.0, 1.0),
+            Point::new(0.0, 0.0),
+        ];
+        assert_eq!(polygon_area(&ring), 1.0);
+        assert_eq!(
+            find_intersections(&ring, &Line::from_coords(-1.0, 0.5, 2.0, 0.5)),
+            vec![Point::new(0.0, 0.5), Point::new(1.0, 0.5)]
+        );
+        assert_eq!(
+            get_all_areas(&ring, &[Line::from_coords(0.5, -1.0, 0.5, 2.0)]).unwrap(),
+            vec![0.5, 0.5]
+        );
+
+        // The closing point only needs to be within the epsilon of the options.
+        let loose = SliceOptions {
+            epsilon: 1e-6,
+            ..SliceOptions::default()
+        };
+        let mut nearly_closed = ring.clone();
+        nearly_closed[4] = Point::new(0.0, 1e-7);
+        let line = Line::from_coords(-1.0, 1.0, 1.0, -1.0);
+        assert_eq!(
+            find_intersections_with_options(&nearly_closed, &line, &loose),
+            vec![Point::new(0.0, 0.0)]
+        );
+
+        // Closed triangle with only two distinct points has no area.
+        let closed_segment = vec![ring[0], ring[1], ring[0]];
+        assert_eq!(polygon_area(&closed_segment), 0.0);
+    }
+
+    #[test]
+    fn reflect_across_line() {
+        let triangle = vec![
+            Point::new(0.0, 1.0),
+            Point::new(2.0, 1.0),
+            Point::new(1.0, 3.0),
+        ];
+        let x_axis = Line::from_coords(-1.0, 0.0, 1.0, 0.0);
+        let reflected = reflect_polygon(&triangle, &x_axis);
+        assert_eq!(
+            reflected,
+            vec![
+                Point::new(0.0, -1.0),
+                Point::new(2.0, -1.0),
+                Point::new(1.0, -3.0),
+            ]
+        );
+        assert!(is_ccw(&triangle));
+        assert!(!is_ccw(&reflected));
+
+        // Across y = x the coordinates swap.
+        let diagonal = Line::from_coords(0.0, 0.0, 1.0, 1.0);
+        assert_eq!(
+            reflect_polygon(&triangle, &diagonal)[2],
+            Point::new(3.0, 1.0)
+        );
+    }
+
+    #[test]
+    fn distance_to_segment() {
+        let seg = Line::from_coords(0.0, 0.0, 2.0, 0.0);
+
+        // Foot of the perpendicular inside the segment.
+        assert_eq!(point_to_segment_distance(&Point::new(1.0, 3.0), &seg), 3.0);
+        assert_eq!(point_to_segment_distance(&Point::new(1.5, -0.5), &seg), 0.5);
+        // Beyond either end the nearest end point counts.
+        assert_eq!(point_to_segment_distance(&Point::new(5.0, 4.0), &seg), 5.0);
+        assert_eq!(point_to_segment_distance(&Point::new(-3.0, 4.0), &seg), 5.0);
+        // Zero length segment.
+        let dot = Line::from_coords(1.0, 1.0, 1.0, 1.0);
+        assert_eq!(point_to_segment_distance(&Point::new(4.0, 5.0), &dot), 5.0);
+
+        assert!(point_on_edge(&Point::new(1.0, 1e-12), &seg, EPSILON));
+        assert!(!point_on_edge(&Point::new(2.0 + 1e-6, 0.0), &seg, EPSILON));
+    }
+
+    #[test]
+    fn snap_to_boundary() {
+        let square = vec![
+            Point::new(0.0, 0.0),
+            Point::new(2.0, 0.0),
+            Point::new(2.0, 2.0),
+            Point::new(0.0, 2.0),
+        ];
+
+        // Right of the square, nearest to the edge from (2, 0) to (2, 2).
+        assert_eq!(
+            closest_boundary_point(&square, &Point::new(3.0, 0.5)),
+            Some((Point::new(2.0, 0.5), 1))
+        );
+        // Outside a corner snaps to the corner itself.
+        assert_eq!(
+            closest_boundary_point(&square, &Point::new(-1.0, 3.0)).map(|(p, _)| p),
+            Some(Point::new(0.0, 2.0))
+        );
+        // Inside works too, the top edge is closest here.
+        assert_eq!(
+            closest_boundary_point(&square, &Point::new(1.0, 1.75)),
+            Some((Point::new(1.0, 2.0), 2))
+        );
+
+        assert_eq!(
+            closest_boundary_point(&square[..2], &Point::new(0.0, 0.0)),
+            None
+        );
+    }
+
+    #[test]
+    fn independent_slice_mode() {
+        let polygon_points = vec![
+            Point::new(0.0, 0.0),
+            Point::new(1.0, 0.0),
+            Point::new(1.0, 1.0),
+            Point::new(0.0, 1.0),
+        ];
+        let lines = vec![
+            Line::from_coords(0.0, 0.0, 1.0, 1.0),
+            Line::from_coords(0.5, 0.0, 0.5, 1.0),
+        ];
+        let independent = SliceOptions {
+            slice_mode: SliceMode::Independent,
+            ..SliceOptions::default()
+        };
+
+        // In sequence the vertical line also cuts both triangles.
+        assert_eq!(get_all_fragments(&polygon_points, &lines).unwrap().len(), 4);
+        assert_eq!(
+            round_area(
+                get_largest_polygon_area(&polygon_points, &lines).unwrap(),
+                6
+            ),
+            0.375
+        );
+
+        // Independently there are two triangles and two rectangles, all halves.
+        let areas = get_all_areas_with_options(&polygon_points, &lines, &independent).unwrap();
+        assert_eq!(areas, vec![0.5, 0.5, 0.5, 0.5]);
+        assert_eq!(
+            get_largest_polygon_area_with_options(&polygon_points, &lines, &independent).unwrap(),
+            0.5
+        );
+        assert_eq!(
+            get_areas_per_cut_with_options(&polygon_points, &lines, &independent).unwrap(),
+            vec![vec![0.5, 0.5], vec![0.5, 0.5]]
+        );
+        assert_eq!(
+            get_all_fragments_with_options(&polygon_points, &[], &independent).unwrap(),
+            vec![polygon_points.clone()]
+        );
+    }
+
+    #[test]
+    fn quarters_adjacency() {
+        let polygon_points = vec![
+            Point::new(0.0, 0.0),
+            Point::new(2.0, 0.0),
+            Point::new(2.0, 2.0),
+            Point::new(0.0, 2.0),
+        ];
+        let lines = vec![
+            Line::from_coords(1.0, -1.0, 1.0, 3.0),
+            Line::from_coords(-1.0, 1.0, 3.0, 1.0),
+        ];
+        let fragments = get_all_fragments(&polygon_points, &lines).unwrap();
+        assert_eq!(fragments.len(), 4);
+
+        let pairs = fragment_adjacency(&fragments);
+        assert_eq!(pairs.len(), 4);
+        for index in 0..fragments.len() {
+            let neighbours = pairs
+                .iter()
+                .filter(|(a, b)| *a == index || *b == index)
+                .count();
+            assert_eq!(neighbours, 2);
+        }
+    }
+
+    #[test]
+    fn merge_cut_pieces() {
+        let square = vec![
+            Point::new(0.0, 0.0),
+            Point::new(1.0, 0.0),
+            Point::new(1.0, 1.0),
+            Point::new(0.0, 1.0),
+        ];
+        let diagonal = Line::from_coords(0.0, 0.0, 1.0, 1.0);
+        let triangles = split_polygon(&square, &diagonal).unwrap();
+
+        let merged = merge_fragments(&triangles[0], &triangles[1], EPSILON).unwrap();
+        assert_eq!(merged.len(), 4);
+        assert!(merged.iter().all(|p| square.contains(p)));
+        assert_eq!(polygon_area(&merged), 1.0);
+        assert!(is_simple(&merged) && is_ccw(&merged));
+
+        // Halves from a vertical cut leave their cut ends mid edge, those go too.
+        let vertical = Line::from_coords(0.5, -1.0, 0.5, 2.0);
+        let halves = split_polygon(&square, &vertical).unwrap();
+        let merged = merge_fragments(&halves[1], &halves[0], EPSILON).unwrap();
+        assert_eq!(merged.len(), 4);
+        assert_eq!(polygon_area(&merged), 1.0);
+
+        // Only a shared corner.
+        let apart: Vec<Point> = square.iter().map(|p| *p + Point::new(1.0, 1.0)).collect();
+        assert_eq!(merge_fragments(&square, &apart, EPSILON), None);
+    }
+
+    #[test]
+    fn chords_inside_polygon() {
+        let square = vec![
+            Point::new(0.0, 0.0),
+            Point::new(1.0, 0.0),
+            Point::new(1.0, 1.0),
+            Point::new(0.0, 1.0),
+        ];
+        let vertical = Line::from_coords(0.5, -1.0, 0.5, 2.0);
+        let chords = cut_chords(&square, &vertical);
+        assert_eq!(chords.len(), 1);
+        assert_eq!(chords[0].p1, Point::new(0.5, 0.0));
+        assert_eq!(chords[0].p2, Point::new(0.5, 1.0));
+        assert_eq!(total_cut_length(&square, &vertical), 1.0);
+
+        // Both prongs of a U, but not the gap between them.
+        let u_shape = vec![
+            Point::new(0.0, 0.0),
+            Point::new(3.0, 0.0),
+            Point::new(3.0, 3.0),
+            Point::new(2.0, 3.0),
+            Point::new(2.0, 1.0),
+            Point::new(1.0, 1.0),
+            Point::new(1.0, 3.0),
+            Point::new(0.0, 3.0),
+        ];
+        let across = Line::from_coords(-1.0, 2.0, 4.0, 2.0);
+        assert_eq!(cut_chords(&u_shape, &across).len(), 2);
+        assert_eq!(total_cut_length(&u_shape, &across), 2.0);
+
+        // Through the inner corners the line only touches them.
+        let through_corners = Line::from_coords(-1.0, 1.0, 4.0, 1.0);
+        assert_eq!(cut_chords(&u_shape, &through_corners).len(), 2);
+        assert_eq!(total_cut_length(&u_shape, &through_corners), 2.0);
+
+        // Along the boundary is no chord.
+        let along_bottom = Line::from_coords(-1.0, 0.0, 4.0, 0.0);
+        assert!(cut_chords(&u_shape, &along_bottom).is_empty());
+    }
+
+    #[test]
+    fn lines_from_angles() {
+        let origin = Point::new(1.0, 2.0);
+        let horizontal = Line::from_point_angle(origin, 0.0, 3.0);
+        assert_eq!(horizontal.p1, origin);
+        assert_eq!(horizontal.p2, Point::new(4.0, 2.0));
+
+        let vertical = Line::from_point_angle(origin, std::f64::consts::FRAC_PI_2, 3.0);
+        assert!(points_equal(&vertical.p2, &Point::new(1.0, 5.0)));
+        assert_eq!(vertical.length(), 3.0);
+
+        // x = 2 and y = 2 in normal form.
+        let x_two = Line::from_rho_theta(2.0, 0.0);
+        assert_eq!(x_two.p1, Point::new(2.0, 0.0));
+        assert_eq!(x_two.p2.x, 2.0);
+        let y_two = Line::from_rho_theta(2.0, std::f64::consts::FRAC_PI_2);
+        assert!(points_equal(&y_two.p1, &Point::new(0.0, 2.0)));
+        assert!((y_two.p2.y - 2.0).abs() < EPSILON);
+
+        let options = SliceOptions {
+            cut_mode: CutMode::Line,
+            ..SliceOptions::default()
+        };
+        let square = vec![
+            Point::new(0.0, 0.0),
+            Point::new(4.0, 0.0),
+            Point::new(4.0, 4.0),
+            Point::new(0.0, 4.0),
+        ];
+        let areas = get_areas_per_cut_with_options(&square, &[x_two], &options).unwrap();
+        assert_eq!(areas, vec![vec![8.0, 8.0]]);
+    }
+
+    #[test]
+    fn line_slope_and_angle() {
+        let horizontal = Line::from_coords(0.0, 1.0, 2.0, 1.0);
+        assert_eq!(horizontal.slope(), Some(0.0));
+        assert_eq!(horizontal.angle(), 0.0);
+
+        let diagonal = Line::from_coords(0.0, 0.0, 2.0, 2.0);
+        assert_eq!(diagonal.slope(), Some(1.0));
+        assert_eq!(diagonal.angle(), std::f64::consts::FRAC_PI_4);
+
+        let vertical = Line::from_coords(1.0, 0.0, 1.0, 2.0);
+        assert_eq!(vertical.slope(), None);
+        assert_eq!(vertical.angle(), std::f64::consts::FRAC_PI_2);
+        assert_eq!(
+            Line::from_coords(1.0, 2.0, 1.0, 0.0).angle(),
+            -std::f64::consts::FRAC_PI_2
+        );
+
+        let angled: Line = Line::from_point_angle(Point::new(0.0, 0.0), 1.0, 1.0);
+        assert!((angled.angle() - 1.0).abs() < EPSILON);
+    }
+
+    #[test]
+    fn area_order_statistics() {
+        let square = vec![
+            Point::new(0.0, 0.0),
+            Point::new(4.0, 0.0),
+            Point::new(4.0, 4.0),
+            Point::new(0.0, 4.0),
+        ];
+        // Pieces of 1.5, 2.5, 4.5 and 7.5.
+        let lines = vec![
+            Line::from_coords(1.0, -1.0, 1.0, 5.0),
+            Line::from_coords(-1.0, 1.5, 5.0, 1.5),
+        ];
+        assert_eq!(nth_largest_area(&square, &lines, 0).unwrap(), Some(7.5));
+        assert_eq!(nth_largest_area(&square, &lines, 1).unwrap(), Some(4.5));
+        assert_eq!(nth_largest_area(&square, &lines, 3).unwrap(), Some(1.5));
+        assert_eq!(nth_largest_area(&square, &lines, 4).unwrap(), None);
+        assert_eq!(median_area(&square, &lines).unwrap(), Some(3.5));
+
+        assert_eq!(median_area(&square, &lines[..1]).unwrap(), Some(8.0));
+    }
+
+    #[test]
+    fn close_crossings_on_concave_polygon_are_kept() {
+        // A notch 1e-4 wide, narrower than the tolerance, cut across.
+        let notched: Vec<Point> = vec![
+            Point::new(0.0, 0.0),
+            Point::new(2.0, 0.0),
+            Point::new(2.0, 2.0),
+            Point::new(1.00005, 2.0),
+            Point::new(1.00005, 1.0),
+            Point::new(0.99995, 1.0),
+            Point::new(0.99995, 2.0),
+            Point::new(0.0, 2.0),
+        ];
+        let options = SliceOptions {
+            epsilon: 1e-3,
+            ..SliceOptions::default()
+        };
+        let line = Line::from_coords(-1.0, 1.5, 3.0, 1.5);
+        let intersections = find_intersections_with_options(&notched, &line, &options);
+        assert_eq!(intersections.len(), 4);
+        assert!((intersections[2].x - intersections[1].x - 1e-4).abs() < 1e-12);
+
+        // Through a vertex the crossing is still found once.
+        let square = vec![
+            Point::new(0.0, 0.0),
+            Point::new(1.0, 0.0),
+            Point::new(1.0, 1.0),
+            Point::new(0.0, 1.0),
+        ];
+        let diagonal = Line::from_coords(-1.0, -1.0, 2.0, 2.0);
+        assert_eq!(
+            find_intersections_with_options(&square, &diagonal, &options),
+            vec![Point::new(0.0, 0.0), Point::new(1.0, 1.0)]
+        );
+    }
+
+    #[test]
+    fn snapping_to_grid() {
+        let noisy = vec![
+            Point::new(1e-12, -2e-12),
+            Point::new(0.9999999999, 0.0),
+            Point::new(1.0000000001, 1.0),
+            Point::new(0.0, 0.9999999999),
+        ];
+        let square = vec![
+            Point::new(0.0, 0.0),
+            Point::new(1.0, 0.0),
+            Point::new(1.0, 1.0),
+            Point::new(0.0, 1.0),
+        ];
+        let snapped = snap_to_grid(&noisy, 1.0);
+        assert_eq!(snapped[1], Point::new(1.0, 0.0));
+        assert_eq!(snapped[2], Point::new(1.0, 1.0));
+        assert_eq!(snapped[3], Point::new(0.0, 1.0));
+        assert_eq!(polygon_area(&snapped), 1.0);
+
+        assert_eq!(
+            snap_to_grid(&[Point::new(0.26, -0.74)], 0.5),
+            vec![Point::new(0.5, -0.5)]
+        );
+
+        // Vertices closer than a cell collapse onto the same grid point.
+        let mut crowded = square.clone();
+        crowded.insert(1, Point::new(0.9, 0.1));
+        let snapped = snap_to_grid(&crowded, 1.0);
+        assert_eq!(snapped.len(), 5);
+        assert_eq!(clean_polygon(&snapped, EPSILON), square);
+    }
+
+    #[test]
+    fn non_finite_coordinates_are_rejected() {
+        let square = vec![
+            Point::new(0.0, 0.0),
+            Point::new(1.0, 0.0),
+            Point::new(1.0, 1.0),
+            Point::new(0.0, 1.0),
+        ];
+        let line = Line::from_coords(0.5, -1.0, 0.5, 2.0);
+        assert_eq!(validate_polygon(&square), Ok(()));
+        assert_eq!(validate_line(&line), Ok(()));
+
+        let mut with_nan = square.clone();
+        with_nan[2].x = f64::NAN;
+        assert_eq!(validate_polygon(&with_nan), Err(SliceError::NonFinite));
+        assert_eq!(split_polygon(&with_nan, &line), Err(SliceError::NonFinite));
+
+        let infinite_line = Line::from_coords(0.5, f64::NEG_INFINITY, 0.5, 2.0);
+        assert_eq!(validate_line(&infinite_line), Err(SliceError::NonFinite));
+        assert_eq!(
+            split_polygon(&square, &infinite_line),
+            Err(SliceError::NonFinite)
+        );
+        let nan_line = Line::from_coords(f64::NAN, 0.5, 2.0, 0.5);
+        assert_eq!(
+            split_polygon(&square, &nan_line),
+            Err(SliceError::NonFinite)
+        );
+
+        // A bad line stops the slicing rather than being skipped.
+        let lines = vec![infinite_line, line];
+        assert_eq!(get_all_areas(&square, &lines), Err(SliceError::NonFinite));
+
+        // Checked up front, even where no line would reach the bad point.
+        assert_eq!(
+            get_largest_polygon_area(&with_nan, &[]),
+            Err(SliceError::NonFinite)
+        );
+        let far_away = Line::from_coords(5.0, 5.0, 6.0, 6.0);
+        assert_eq!(
+            fragment_count(&with_nan, std::slice::from_ref(&far_away)),
+            Err(SliceError::NonFinite)
+        );
+        let mut with_infinity = square.clone();
+        with_infinity[0].y = f64::INFINITY;
+        assert_eq!(
+            median_area(&with_infinity, std::slice::from_ref(&far_away)),
+            Err(SliceError::NonFinite)
+        );
+
+        // Queries for a single line find nothing instead of misordered points.
+        assert!(find_intersections(&with_nan, &lines[1]).is_empty());
+        assert!(find_intersections(&square, &lines[0]).is_empty());
+        assert!(classify_crossings(&with_nan, &lines[1]).is_empty());
+        assert_eq!(edges_crossed(&with_infinity, &lines[1]), 0);
+    }
+
+    #[test]
+    fn only_lines_missing_a_polygon_keep_it() {
+        let square = vec![
+            Point::new(0.0, 0.0),
+            Point::new(1.0, 0.0),
+            Point::new(1.0, 1.0),
+            Point::new(0.0, 1.0),
+        ];
+        let cut = Line::from_coords(0.5, -1.0, 0.5, 2.0);
+        let miss = Line::from_coords(2.0, -1.0, 2.0, 2.0);
+        assert_eq!(
+            get_all_areas(&square, &[cut.clone(), miss]),
+            Ok(vec![0.5, 0.5])
+        );
+
+        let point = Line::from_coords(0.5, 0.5, 0.5, 0.5);
+        assert_eq!(
+            get_all_areas(&square, &[cut.clone(), point.clone()]),
+            Err(SliceError::DegenerateLine)
+        );
+        let options = SliceOptions {
+            slice_mode: SliceMode::Independent,
+            ..SliceOptions::default()
+        };
+        assert_eq!(
+            get_all_fragments_with_options(&square, std::slice::from_ref(&point), &options),
+            Err(SliceError::DegenerateLine)
+        );
+
+        let mut slicer = Slicer::new(square.clone());
+        assert_eq!(slicer.add_cut(&cut), Ok(()));
+        assert_eq!(slicer.add_cut(&point), Err(SliceError::DegenerateLine));
+        assert_eq!(slicer.fragments().len(), 2);
+    }
+
+    #[test]
+    fn polygons_equal_up_to_start_and_winding() {
+        let triangle = vec![
+            Point::new(0.0, 0.0),
+            Point::new(2.0, 0.0),
+            Point::new(1.0, 1.0),
+        ];
+        let rotated = vec![
+            Point::new(1.0, 1.0),
+            Point::new(0.0, 0.0),
+            Point::new(2.0, 0.0),
+        ];
+        let reversed = vec![
+            Point::new(2.0, 0.0),
+            Point::new(0.0, 0.0),
+            Point::new(1.0, 1.0),
+        ];
+        let noisy = vec![
+            Point::new(1.0, 1.0 + 1e-12),
+            Point::new(2.0, 0.0),
+            Point::new(-1e-12, 0.0),
+        ];
+        assert!(polygon_eq(&triangle, &triangle, EPSILON));
+        assert!(polygon_eq(&triangle, &rotated, EPSILON));
+        assert!(polygon_eq(&triangle, &reversed, EPSILON));
+        assert!(polygon_eq(&triangle, &noisy, EPSILON));
+        assert!(!polygon_eq(&triangle, &noisy, 0.0));
+
+        let other = vec![
+            Point::new(0.0, 0.0),
+            Point::new(2.0, 0.0),
+            Point::new(1.0, 2.0),
+        ];
+        assert!(!polygon_eq(&triangle, &other, EPSILON));
+        assert!(!polygon_eq(&triangle, &triangle[..2], EPSILON));
+
+        // Same points in a different order make a different outline.
+        let square = vec![
+            Point::new(0.0, 0.0),
+            Point::new(1.0, 0.0),
+            Point::new(1.0, 1.0),
+            Point::new(0.0, 1.0),
+        ];
+        let bowtie = vec![
+            Point::new(0.0, 0.0),
+            Point::new(1.0, 1.0),
+            Point::new(1.0, 0.0),
+            Point::new(0.0, 1.0),
+        ];
+        assert!(!polygon_eq(&square, &bowtie, EPSILON));
+    }
+
+    #[test]
+    fn crossings_alternate_entry_and_exit() {
+        use crate::CrossingKind::{Entry, Exit};
+
+        let mut u_shape = vec![
+            Point::new(0.0, 0.0),
+            Point::new(3.0, 0.0),
+            Point::new(3.0, 3.0),
+            Point::new(2.0, 3.0),
+            Point::new(2.0, 1.0),
+            Point::new(1.0, 1.0),
+            Point::new(1.0, 3.0),
+            Point::new(0.0, 3.0),
+        ];
+        let across = Line::from_coords(-1.0, 2.0, 4.0, 2.0);
+        let expected = vec![
+            (Point::new(0.0, 2.0), Entry),
+            (Point::new(1.0, 2.0), Exit),
+            (Point::new(2.0, 2.0), Entry),
+            (Point::new(3.0, 2.0), Exit),
+        ];
+        assert_eq!(classify_crossings(&u_shape, &across), expected);
+
+        // Winding doesn't matter, the direction of the line does.
+        u_shape.reverse();
+        assert_eq!(classify_crossings(&u_shape, &across), expected);
+        let backwards = Line::new(across.p2, across.p1);
+        let crossings = classify_crossings(&u_shape, &backwards);
+        assert_eq!(crossings[0], (Point::new(3.0, 2.0), Entry));
+        assert_eq!(crossings[3], (Point::new(0.0, 2.0), Exit));
+
+        // The floor of the notch is boundary with the outside above it,
+        // so the line leaves at one inner corner and comes back at the other.
+        let through_corners = Line::from_coords(-1.0, 1.0, 4.0, 1.0);
+        let expected = vec![
+            (Point::new(0.0, 1.0), Entry),
+            (Point::new(1.0, 1.0), Exit),
+            (Point::new(2.0, 1.0), Entry),
+            (Point::new(3.0, 1.0), Exit),
+        ];
+        assert_eq!(classify_crossings(&u_shape, &through_corners), expected);
+
+        // Likewise at the tip of a notch, where both are at the same point.
+        let mut m_shape = vec![
+            Point::new(0.0, 0.0),
+            Point::new(2.0, 0.0),
+            Point::new(2.0, 2.0),
+            Point::new(1.0, 0.5),
+            Point::new(0.0, 2.0),
+        ];
+        let through_tip = Line::from_coords(3.0, 0.5, -1.0, 0.5);
+        let expected = vec![
+            (Point::new(2.0, 0.5), Entry),
+            (Point::new(1.0, 0.5), Exit),
+            (Point::new(1.0, 0.5), Entry),
+            (Point::new(0.0, 0.5), Exit),
+        ];
+        assert_eq!(classify_crossings(&m_shape, &through_tip), expected);
+        m_shape.reverse();
+        assert_eq!(classify_crossings(&m_shape, &through_tip), expected);
+
+        // Along the inner edge of an L the line leaves where that edge starts.
+        let l_shape = vec![
+            Point::new(0.0, 0.0),
+            Point::new(2.0, 0.0),
+            Point::new(2.0, 1.0),
+            Point::new(1.0, 1.0),
+            Point::new(1.0, 2.0),
+            Point::new(0.0, 2.0),
+        ];
+        assert_eq!(
+            classify_crossings(&l_shape, &through_corners),
+            vec![(Point::new(0.0, 1.0), Entry), (Point::new(1.0, 1.0), Exit)]
+        );
+    }
+
+    #[test]
+    fn slicer_adds_cuts_one_at_a_time() {
+        let polygon_points = vec![
+            Point::new(0.0, 0.0),
+            Point::new(1.0, 0.0),
+            Point::new(1.0, 1.0),
+            Point::new(0.0, 1.0),
+        ];
+        let lines = vec![
+            Line::from_coords(0.0, 0.0, 1.0, 1.0),
+            Line::from_coords(0.5, 0.0, 0.5, 1.0),
+        ];
+
+        let mut slicer = Slicer::new(polygon_points.clone());
+        assert_eq!(slicer.fragments().len(), 1);
+        assert_eq!(slicer.largest_area(), 1.0);
+
+        slicer.add_cut(&lines[0]).unwrap();
+        assert_eq!(slicer.fragments().len(), 2);
+        assert_eq!(slicer.largest_area(), 0.5);
+
+        slicer.add_cut(&lines[1]).unwrap();
+        assert_eq!(slicer.fragments().len(), 4);
+        assert_eq!(
+            slicer.largest_area(),
+            get_largest_polygon_area(&polygon_points, &lines).unwrap()
+        );
+        assert_eq!(
+            slicer.fragments(),
+            &get_all_fragments(&polygon_points, &lines).unwrap()[..]
+        );
+    }
+
+    #[test]
+    fn slicer_undo() {
+        let polygon_points = vec![
+            Point::new(0.0, 0.0),
+            Point::new(1.0, 0.0),
+            Point::new(1.0, 1.0),
+            Point::new(0.0, 1.0),
+        ];
+        let line = Line::from_coords(0.5, -1.0, 0.5, 2.0);
+
+        let original = [polygon_points.clone()];
+
+        let mut slicer = Slicer::new(polygon_points);
+        slicer.undo();
+        assert_eq!(slicer.fragments(), &original[..]);
+
+        slicer.add_cut(&line).unwrap();
+        let after_cut = slicer.fragments().to_vec();
+        slicer.undo();
+        assert_eq!(slicer.fragments(), &original[..]);
+        slicer.add_cut(&line).unwrap();
+        assert_eq!(slicer.fragments(), &after_cut[..]);
+
+        // Only the most recent cuts are remembered. Each cut across a long
+        // strip adds a fragment, so the count tells how far back undo went.
+        let limit = Slicer::<f64>::UNDO_LIMIT;
+        let length = (limit + 2) as f64;
+        let strip = vec![
+            Point::new(0.0, 0.0),
+            Point::new(length, 0.0),
+            Point::new(length, 1.0),
+            Point::new(0.0, 1.0),
+        ];
+        let mut slicer = Slicer::new(strip);
+        for cut in 1..=limit + 1 {
+            let x = cut as f64;
+            slicer.add_cut(&Line::from_coords(x, -1.0, x, 2.0)).unwrap();
+            assert_eq!(slicer.fragments().len(), cut + 1);
+        }
+        for _ in 0..limit + 1 {
+            slicer.undo();
+        }
+        // Back to after the first cut, the uncut strip was forgotten.
+        assert_eq!(slicer.fragments().len(), 2);
+    }
+
+    #[test]
+    fn clipping_to_rectangle() {
+        let (min, max) = (Point::new(0.0, 0.0), Point::new(4.0, 2.0));
+
+        let inside = vec![
+            Point::new(1.0, 0.5),
+            Point::new(3.0, 0.5),
+            Point::new(2.0, 1.5),
+        ];
+        assert_eq!(clip_to_rect(&inside, min, max), inside);
+
+        let outside = vec![
+            Point::new(5.0, 0.0),
+            Point::new(6.0, 0.0),
+            Point::new(6.0, 1.0),
+        ];
+        assert!(clip_to_rect(&outside, min, max).is_empty());
+
+        // A triangle poking out past the top right corner,
+        // its long edge running through the corner itself.
+        let corner = vec![
+            Point::new(3.0, 1.0),
+            Point::new(5.0, 1.0),
+            Point::new(3.0, 3.0),
+        ];
+        let clipped = clip_to_rect(&corner, min, max);
+        assert_eq!(polygon_area(&clipped), 1.0);
+        assert!(clipped.contains(&Point::new(4.0, 1.0)));
+        assert!(clipped.contains(&Point::new(3.0, 2.0)));
+        assert!(clipped.contains(&Point::new(4.0, 2.0)));
+
+        // Sticking out on all four sides leaves the rectangle itself.
+        let diamond = vec![
+            Point::new(2.0, -3.0),
+            Point::new(7.0, 1.0),
+            Point::new(2.0, 5.0),
+            Point::new(-3.0, 1.0),
+        ];
+        let clipped = clip_to_rect(&diamond, min, max);
+        assert_eq!(polygon_area(&clipped), 8.0);
+        assert_eq!(
+            polygon_area(&clipped),
+            polygon_area(&clip_polygon(
+                &diamond,
+                &[min, Point::new(4.0, 0.0), max, Point::new(0.0, 2.0)]
+            ))
+        );
+    }
+
+    #[test]
+    fn union_of_convex_polygons() {
+        let square = |x: f64, y: f64, size: f64| {
+            vec![
+                Point::new(x, y),
+                Point::new(x + size, y),
+                Point::new(x + size, y + size),
+                Point::new(x, y + size),
+            ]
+        };
+
+        // Overlapping by a quarter, leaving an outline with two notches.
+        let union = union_convex(&square(0.0, 0.0, 2.0), &square(1.0, 1.0, 2.0)).unwrap();
+        assert_eq!(union.len(), 8);
+        assert_eq!(polygon_signed_area(&union), 7.0);
+        assert!(is_simple(&union));
+
+        // One inside the other.
+        let union = union_convex(&square(0.0, 0.0, 4.0), &square(1.0, 1.0, 1.0)).unwrap();
+        assert!(polygon_eq(&union, &square(0.0, 0.0, 4.0), EPSILON));
+
+        // Touching along a whole edge or part of one.
+        let union = union_convex(&square(0.0, 0.0, 1.0), &square(1.0, 0.0, 1.0)).unwrap();
+        assert_eq!(union.len(), 4);
+        assert_eq!(polygon_area(&union), 2.0);
+        let union = union_convex(&square(0.0, 0.0, 2.0), &square(2.0, 0.5, 1.0)).unwrap();
+        assert_eq!(union.len(), 8);
+        assert_eq!(polygon_area(&union), 5.0);
+
+        // Only touching in a corner, or apart.
+        assert!(union_convex(&square(0.0, 0.0, 1.0), &square(1.0, 1.0, 1.0)).is_none());
+        assert!(union_convex(&square(0.0, 0.0, 1.0), &square(3.0, 0.0, 1.0)).is_none());
+    }
+
+    #[test]
+    fn overlapping_area() {
+        let unit_square = vec![
+            Point::new(0.0, 0.0),
+            Point::new(1.0, 0.0),
+            Point::new(1.0, 1.0),
+            Point::new(0.0, 1.0),
+        ];
+        let shifted: Vec<Point> = unit_square
+            .iter()
+            .map(|p| *p + Point::new(0.5, 0.0))
+            .collect();
+        let apart: Vec<Point> = unit_square
+            .iter()
+            .map(|p| *p + Point::new(2.0, 0.0))
+            .collect();
+
+        assert_eq!(intersection_area(&unit_square, &unit_square), 1.0);
+        assert_eq!(intersection_area(&unit_square, &shifted), 0.5);
+        assert_eq!(intersection_area(&shifted, &unit_square), 0.5);
+        assert_eq!(intersection_area(&unit_square, &apart), 0.0);
+
+        // A concave polygon clipped by a convex one.
+        let u_shape = vec![
+            Point::new(0.0, 0.0),
+            Point::new(3.0, 0.0),
+            Point::new(3.0, 3.0),
+            Point::new(2.0, 3.0),
+            Point::new(2.0, 1.0),
+            Point::new(1.0, 1.0),
+            Point::new(1.0, 3.0),
+            Point::new(0.0, 3.0),
+        ];
+        let band = vec![
+            Point::new(-1.0, 0.5),
+            Point::new(4.0, 0.5),
+            Point::new(4.0, 2.0),
+            Point::new(-1.0, 2.0),
+        ];
+        assert_eq!(intersection_area(&u_shape, &band), 3.5);
+    }
+
+    #[test]
+    fn counting_fragments() {
+        let polygon_points = vec![
+            Point::new(0.0, 0.0),
+            Point::new(1.0, 0.0),
+            Point::new(1.0, 1.0),
+            Point::new(0.0, 1.0),
+        ];
+        let lines = vec![
+            Line::from_coords(0.0, 0.0, 1.0, 1.0),
+            Line::from_coords(0.5, 0.0, 0.5, 1.0),
+        ];
+        assert_eq!(fragment_count(&polygon_points, &lines).unwrap(), 4);
+        assert_eq!(fragment_count(&polygon_points, &lines[..1]).unwrap(), 2);
+        assert_eq!(fragment_count(&polygon_points, &[]).unwrap(), 1);
+
+        let missing = vec![Line::from_coords(2.0, 0.0, 2.0, 1.0)];
+        assert_eq!(fragment_count(&polygon_points, &missing).unwrap(), 1);
+
+        // A comb whose teeth each line cuts separately, counted in every mode.
+        let comb = vec![
+            Point::new(0.0, 0.0),
+            Point::new(5.0, 0.0),
+            Point::new(5.0, 3.0),
+            Point::new(4.0, 3.0),
+            Point::new(4.0, 1.0),
+            Point::new(3.0, 1.0),
+            Point::new(3.0, 3.0),
+            Point::new(2.0, 3.0),
+            Point::new(2.0, 1.0),
+            Point::new(1.0, 1.0),
+            Point::new(1.0, 3.0),
+            Point::new(0.0, 3.0),
+        ];
+        let lines = vec![
+            Line::from_coords(-1.0, 2.0, 6.0, 2.0),
+            Line::from_coords(0.5, -1.0, 0.5, 4.0),
+            Line::from_coords(-1.0, 2.5, 2.5, 2.5),
+        ];
+        for slice_mode in [SliceMode::Sequential, SliceMode::Independent] {
+            for cut_mode in [CutMode::Segment, CutMode::Line] {
+                let options = SliceOptions {
+                    slice_mode,
+                    cut_mode,
+                    ..SliceOptions::default()
+                };
+                assert_eq!(
+                    fragment_count_with_options(&comb, &lines, &options).unwrap(),
+                    get_all_fragments_with_options(&comb, &lines, &options)
+                        .unwrap()
+                        .len()
+                );
+            }
+        }
+        assert_eq!(fragment_count(&comb, &lines[..1]).unwrap(), 4);
+    }
+
+    #[test]
+    fn quantized_points_in_a_set() {
+        let cell = 1e-6;
+        let a = Point::new(0.3, 1.7);
+        let b = Point::new(0.3 + EPSILON, 1.7 - EPSILON);
+        let far = Point::new(0.3 + 1e-5, 1.7);
+        assert_ne!(a, b);
+        assert_eq!(
+            a.quantized(cell),
+            QuantizedPoint {
+                x: 300_000,
+                y: 1_700_000
+            }
+        );
+
+        let set: std::collections::HashSet<QuantizedPoint> =
+            [a, b, far].iter().map(|p| p.quantized(cell)).collect();
+        assert_eq!(set.len(), 2);
+        assert!(set.contains(&b.quantized(cell)));
+        assert!(set.contains(&far.quantized(cell)));
+    }
+
+    #[test]
+    fn several_polygons_cut_by_the_same_lines() {
+        let square = |x: f64, size: f64| {
+            vec![
+                Point::new(x, 0.0),
+                Point::new(x + size, 0.0),
+                Point::new(x + size, size),
+                Point::new(x, size),
+            ]
+        };
+        let polygons = vec![square(0.0, 2.0), square(3.0, 1.0)];
+
+        // Only reaches across the first square.
+        let lines = vec![Line::from_coords(1.0, -1.0, 1.0, 3.0)];
+        let fragments = get_all_fragments_multi(&polygons, &lines).unwrap();
+        let sources: Vec<usize> = fragments.iter().map(|(index, _)| *index).collect();
+        assert_eq!(sources, vec![0, 0, 1]);
+        assert_eq!(fragments[2].1, polygons[1]);
+
+        let through_second = vec![Line::from_coords(3.5, -1.0, 3.5, 2.0)];
+        let fragments = get_all_fragments_multi(&polygons, &through_second).unwrap();
+        assert_eq!(fragments.len(), 3);
+        assert_eq!(fragments[0], (0, polygons[0].clone()));
+        assert!(fragments[1..]
+            .iter()
+            .all(|(index, fragment)| *index == 1 && polygon_area(fragment) == 0.5));
+        assert_eq!(
+            get_all_areas_multi(&polygons, &lines).unwrap(),
+            vec![2.0, 2.0, 1.0]
+        );
+        assert_eq!(
+            get_largest_polygon_area_multi(&polygons, &lines).unwrap(),
+            2.0
+        );
+
+        // Without any lines the largest square wins.
+        assert_eq!(get_largest_polygon_area_multi(&polygons, &[]).unwrap(), 4.0);
+        assert_eq!(
+            get_largest_polygon_area_multi::<f64>(&[], &lines).unwrap(),
+            0.0
+        );
+    }
+
+    #[test]
+    fn exact_area_on_integer_grid() {
+        let triangle = vec![Point2i::new(0, 0), Point2i::new(3, 0), Point2i::new(0, 3)];
+        assert_eq!(polygon_area_exact(&triangle), Some(9));
+        let clockwise: Vec<Point2i> = triangle.iter().rev().copied().collect();
+        assert_eq!(polygon_area_exact(&clockwise), Some(-9));
+
+        // Past 2^53 not every integer coordinate even fits in an f64.
+        let far = 1_i64 << 53;
+        let offset: Vec<Point2i> = triangle
+            .iter()
+            .map(|p| Point2i::new(p.x + far, p.y + far))
+            .collect();
+        assert_eq!(polygon_area_exact(&offset), Some(9));
+
+        let snapped: Vec<Point2i> = [
+            Point::new(0.1, -0.2),
+            Point::new(2.0, 0.1),
+            Point::new(0.9, 1.1),
+        ]
+        .iter()
+        .map(|p| p.quantized(1.0).into())
+        .collect();
+        assert_eq!(polygon_area_exact(&snapped), Some(2));
+        assert_eq!(polygon_area_exact(&triangle[..2]), Some(0));
+
+        // Twice the area of a square just inside +-2^62 is just below 2^127.
+        let square = |half: i64| {
+            vec![
+                Point2i::new(-half, -half),
+                Point2i::new(half, -half),
+                Point2i::new(half, half),
+                Point2i::new(-half, half),
+            ]
+        };
+        let half = (1_i64 << 62) - 1;
+        assert_eq!(
+            polygon_area_exact(&square(half)),
+            Some(8 * half as i128 * half as i128)
+        );
+        assert_eq!(polygon_area_exact(&square(1 << 62)), None);
+
+        // Spanning the whole i64 range.
+        let corners = vec![
+            Point2i::new(i64::MIN, i64::MIN),
+            Point2i::new(i64::MAX, i64::MIN),
+            Point2i::new(i64::MAX, i64::MAX),
+            Point2i::new(i64::MIN, i64::MAX),
+        ];
+        assert_eq!(polygon_area_exact(&corners), None);
+    }
+
+    #[test]
+    fn shoelace_matches_triangulation() {
+        use crate::testing::{random_convex_polygon, random_simple_polygon};
+
+        for seed in 0..200 {
+            let vertex_count = 3 + seed as usize % 30;
+            for mut polygon in [
+                random_simple_polygon(vertex_count, seed),
+                random_convex_polygon(vertex_count, seed),
+            ] {
+                let expected = area_via_triangulation(&polygon);
+                assert!(
+                    (polygon_area(&polygon) - expected).abs() < EPSILON,
+                    "seed {}: {} instead of {}",
+                    seed,
+                    polygon_area(&polygon),
+                    expected
+                );
+
+                polygon.reverse();
+                assert!((polygon_area(&polygon) - expected).abs() < EPSILON);
+                assert!((polygon_signed_area(&polygon) + expected).abs() < EPSILON);
+            }
+        }
+    }
+
+    #[test]
+    fn segments_intersect() {
+        let diagonal = Line::from_coords(0.0, 0.0, 2.0, 2.0);
+
+        let crossing = Line::from_coords(0.0, 2.0, 2.0, 0.0);
+        assert!(diagonal.intersects(&crossing));
+        assert!(crossing.intersects(&diagonal));
+
+        let touching_end = Line::from_coords(2.0, 2.0, 3.0, 0.0);
+        assert!(diagonal.intersects(&touching_end));
+        let touching_middle = Line::from_coords(1.0, 1.0, 2.0, 0.0);
+        assert!(diagonal.intersects(&touching_middle));
+
+        let parallel = Line::from_coords(1.0, 0.0, 3.0, 2.0);
+        assert!(!diagonal.intersects(&parallel));
+        let short = Line::from_coords(2.0, 0.0, 1.5, 0.5);
+        assert!(!diagonal.intersects(&short));
+
+        let overlapping = Line::from_coords(1.0, 1.0, 3.0, 3.0);
+        assert!(diagonal.intersects(&overlapping));
+        let same_line_apart = Line::from_coords(3.0, 3.0, 4.0, 4.0);
+        assert!(!diagonal.intersects(&same_line_apart));
+        let vertical = Line::from_coords(0.0, 0.0, 0.0, 2.0);
+        assert!(!vertical.intersects(&Line::from_coords(0.0, 3.0, 0.0, 4.0)));
+
+        let point = Line::from_coords(1.0, 1.0, 1.0, 1.0);
+        assert!(!diagonal.intersects(&point));
+
+        // Agrees with the full computation apart from the tolerance.
+        for other in [&crossing, &parallel, &short, &overlapping, &same_line_apart] {
+            assert_eq!(
+                diagonal.intersects(other),
+                segment_intersection(&diagonal, other) != Intersection::None
+            );
+        }
+    }
+
+    #[test]
+    fn intersection_parameters() {
+        let horizontal = Line::from_coords(0.0, 1.0, 4.0, 1.0);
+        let vertical = Line::from_coords(2.0, 0.0, 2.0, 4.0);
+        assert_eq!(
+            intersection_params(&horizontal, &vertical),
+            Some((0.5, 0.25))
+        );
+        assert_eq!(
+            intersection_params(&vertical, &horizontal),
+            Some((0.25, 0.5))
+        );
+
+        // The infinite lines meet beyond the ends of the segments.
+        let short = Line::from_coords(6.0, 2.0, 6.0, 3.0);
+        assert_eq!(intersection_params(&horizontal, &short), Some((1.5, -1.0)));
+        assert_eq!(line_segment_intersection(&horizontal, &short), None);
+
+        let parallel = Line::from_coords(0.0, 2.0, 4.0, 2.0);
+        assert_eq!(intersection_params(&horizontal, &parallel), None);
+    }
+
+    #[test]
+    fn angles_and_reflex_vertices() {
+        use std::f64::consts::{FRAC_PI_2, PI};
+
+        let square = vec![
+            Point::new(0.0, 0.0),
+            Point::new(1.0, 0.0),
+            Point::new(1.0, 1.0),
+            Point::new(0.0, 1.0),
+        ];
+        assert_eq!(interior_angles(&square), vec![FRAC_PI_2; 4]);
+        assert!(reflex_vertices(&square).is_empty());
+
+        let mut l_shape = vec![
+            Point::new(0.0, 0.0),
+            Point::new(2.0, 0.0),
+            Point::new(2.0, 1.0),
+            Point::new(1.0, 1.0),
+            Point::new(1.0, 2.0),
+            Point::new(0.0, 2.0),
+        ];
+        let angles = interior_angles(&l_shape);
+        assert_eq!(angles[3], 3.0 * FRAC_PI_2);
+        let total: f64 = angles.iter().sum();
+        assert!((total - 4.0 * PI).abs() < EPSILON);
+        assert_eq!(reflex_vertices(&l_shape), vec![3]);
+
+        // Same angles the other way around.
+        l_shape.reverse();
+        assert_eq!(interior_angles(&l_shape)[2], 3.0 * FRAC_PI_2);
+        assert_eq!(reflex_vertices(&l_shape), vec![2]);
+
+        // A vertex in the middle of an edge is straight, not reflex.
+        let with_midpoint = vec![
+            Point::new(0.0, 0.0),
+            Point::new(1.0, 0.0),
+            Point::new(2.0, 0.0),
+            Point::new(1.0, 1.0),
+        ];
+        assert_eq!(interior_angles(&with_midpoint)[1], PI);
+        assert!(reflex_vertices(&with_midpoint).is_empty());
+    }
+
+    #[test]
+    fn convex_decomposition() {
+        let l_shape = vec![
+            Point::new(0.0, 0.0),
+            Point::new(2.0, 0.0),
+            Point::new(2.0, 1.0),
+            Point::new(1.0, 1.0),
+            Point::new(1.0, 2.0),
+            Point::new(0.0, 2.0),
+        ];
+        let pieces = decompose_convex(&l_shape);
+        assert_eq!(pieces.len(), 2);
+        assert!(pieces.iter().all(|piece| is_convex(piece) && is_ccw(piece)));
+        let total: f64 = pieces.iter().map(|piece| polygon_area(piece)).sum();
+        assert!((total - 3.0).abs() < EPSILON);
+
+        // A convex polygon stays in one piece.
+        let square = vec![
+            Point::new(0.0, 0.0),
+            Point::new(1.0, 0.0),
+            Point::new(1.0, 1.0),
+            Point::new(0.0, 1.0),
+        ];
+        let pieces = decompose_convex(&square);
+        assert_eq!(pieces.len(), 1);
+        assert!(polygon_eq(&pieces[0], &square, EPSILON));
+
+        let comb = vec![
+            Point::new(0.0, 0.0),
+            Point::new(5.0, 0.0),
+            Point::new(5.0, 2.0),
+            Point::new(4.0, 2.0),
+            Point::new(4.0, 1.0),
+            Point::new(3.0, 1.0),
+            Point::new(3.0, 2.0),
+            Point::new(2.0, 2.0),
+            Point::new(2.0, 1.0),
+            Point::new(1.0, 1.0),
+            Point::new(1.0, 2.0),
+            Point::new(0.0, 2.0),
+        ];
+        for seed in 0..50 {
+            let polygon = crate::testing::random_simple_polygon(12, seed);
+            for shape in [&comb, &polygon] {
+                let pieces = decompose_convex(shape);
+                assert!(pieces.iter().all(|piece| is_convex(piece)));
+                let total: f64 = pieces.iter().map(|piece| polygon_area(piece)).sum();
+                assert!((total - polygon_area(shape)).abs() < EPSILON);
+            }
+        }
+    }
+
+    #[test]
+    fn smallest_enclosing_circle() {
+        let square = vec![
+            Point::new(0.0, 0.0),
+            Point::new(1.0, 0.0),
+            Point::new(1.0, 1.0),
+            Point::new(0.0, 1.0),
+        ];
+        let (center, radius) = min_enclosing_circle(&square).unwrap();
+        assert!(points_equal(&center, &Point::new(0.5, 0.5)));
+        assert!((radius - 0.5f64.sqrt()).abs() < EPSILON);
+
+        // An obtuse triangle's circle only touches the ends of its long side.
+        let obtuse = vec![
+            Point::new(0.0, 0.0),
+            Point::new(4.0, 0.0),
+            Point::new(2.0, 1.0),
+        ];
+        assert_eq!(
+            min_enclosing_circle(&obtuse),
+            Some((Point::new(2.0, 0.0), 2.0))
+        );
+
+        let collinear = vec![
+            Point::new(1.0, 1.0),
+            Point::new(0.0, 0.0),
+            Point::new(3.0, 3.0),
+        ];
+        let (center, radius) = min_enclosing_circle(&collinear).unwrap();
+        assert!(points_equal(&center, &Point::new(1.5, 1.5)));
+        assert!((radius - 4.5f64.sqrt()).abs() < EPSILON);
+
+        for seed in 0..50 {
+            let polygon = crate::testing::random_simple_polygon(20, seed);
+            let (center, radius) = min_enclosing_circle(&polygon).unwrap();
+            assert!(polygon
+                .iter()
+                .all(|p| center.distance(p) <= radius + EPSILON));
+            // At least two points on the circle, or it could shrink.
+            let on_circle = polygon
+                .iter()
+                .filter(|p| (center.distance(p) - radius).abs() < 1e-9)
+                .count();
+            assert!(on_circle >= 2);
+        }
+
+        assert_eq!(min_enclosing_circle::<f64>(&[]), None);
+        assert_eq!(
+            min_enclosing_circle(&[Point::new(1.0, 2.0)]),
+            Some((Point::new(1.0, 2.0), 0.0))
+        );
+    }
+
+    #[test]
+    fn diameter() {
+        let square = vec![
+            Point::new(0.0, 0.0),
+            Point::new(1.0, 0.0),
+            Point::new(1.0, 1.0),
+            Point::new(0.0, 1.0),
+        ];
+        assert!((polygon_diameter(&square) - 2f64.sqrt()).abs() < EPSILON);
+
+        assert_eq!(polygon_diameter::<f64>(&[]), 0.0);
+        assert_eq!(polygon_diameter(&[Point::new(1.0, 2.0)]), 0.0);
+        assert_eq!(
+            polygon_diameter(&[Point::new(0.0, 0.0), Point::new(3.0, 4.0)]),
+            5.0
+        );
+
+        for seed in 0..50 {
+            let polygon = crate::testing::random_simple_polygon(20, seed);
+            let brute_force = polygon
+                .iter()
+                .flat_map(|a| polygon.iter().map(move |b| a.distance(b)))
+                .fold(0.0, f64::max);
+            assert!((polygon_diameter(&polygon) - brute_force).abs() < EPSILON);
+        }
+    }
+
+    #[test]
+    fn edges_crossed_by_cut() {
+        let plus = vec![
+            Point::new(-0.5, -1.5),
+            Point::new(0.5, -1.5),
+            Point::new(0.5, -0.5),
+            Point::new(1.5, -0.5),
+            Point::new(1.5, 0.5),
+            Point::new(0.5, 0.5),
+            Point::new(0.5, 1.5),
+            Point::new(-0.5, 1.5),
+            Point::new(-0.5, 0.5),
+            Point::new(-1.5, 0.5),
+            Point::new(-1.5, -0.5),
+            Point::new(-0.5, -0.5),
+        ];
+        // Straight through the middle, in one end of the plus and out the other.
+        assert_eq!(
+            edges_crossed(&plus, &Line::from_coords(-2.0, 0.0, 2.0, 0.0)),
+            2
+        );
+        // Diagonally across the top and right arms, leaving through the notch between them.
+        let diagonal = Line::from_coords(-2.0, 3.2, 3.2, -2.0);
+        assert_eq!(edges_crossed(&plus, &diagonal), 4);
+        assert_eq!(find_intersections(&plus, &diagonal).len(), 4);
+
+        // Only the segment counts unless the line is extended.
+        let short = Line::from_coords(-0.4, 1.6, 0.6, 0.6);
+        assert_eq!(edges_crossed(&plus, &short), 2);
+        let options = SliceOptions {
+            cut_mode: CutMode::Line,
+            ..SliceOptions::default()
+        };
+        assert_eq!(edges_crossed_with_options(&plus, &short, &options), 4);
+
+        // Each corner the diagonal passes through is counted on both of its edges.
+        let square = vec![
+            Point::new(0.0, 0.0),
+            Point::new(1.0, 0.0),
+            Point::new(1.0, 1.0),
+            Point::new(0.0, 1.0),
+        ];
+        let corners = Line::from_coords(-1.0, -1.0, 2.0, 2.0);
+        assert_eq!(edges_crossed(&square, &corners), 4);
+        assert_eq!(find_intersections(&square, &corners).len(), 2);
+    }
+
+    #[test]
+    fn polygon_methods() {
+        let exterior = vec![
+            Point::new(0.0, 0.0),
+            Point::new(4.0, 0.0),
+            Point::new(4.0, 4.0),
+            Point::new(0.0, 4.0),
+        ];
+        let solid = Polygon::new(exterior.clone());
+        assert_eq!(solid.area(), polygon_area(&exterior));
+        assert_eq!(solid.perimeter(), polygon_perimeter(&exterior));
+        assert_eq!(solid.centroid(), polygon_centroid(&exterior));
+        assert!(solid.contains(&Point::new(1.0, 1.0)));
+
+        // A hole in the lower left corner moves the centroid up and right.
+        let hole = vec![
+            Point::new(0.5, 0.5),
+            Point::new(0.5, 1.5),
+            Point::new(1.5, 1.5),
+            Point::new(1.5, 0.5),
+        ];
+        let polygon = Polygon::with_holes(exterior, vec![hole]);
+        assert_eq!(polygon.area(), 15.0);
+        assert_eq!(polygon.perimeter(), 20.0);
+        let centroid = polygon.centroid().unwrap();
+        assert!(points_equal(
+            &centroid,
+            &Point::new(31.0 / 15.0, 31.0 / 15.0)
+        ));
+
+        assert!(!polygon.contains(&Point::new(1.0, 1.0)));
+        assert!(polygon.contains(&Point::new(3.0, 3.0)));
+        assert!(!polygon.contains(&Point::new(5.0, 5.0)));
+
+        // Nothing left once the hole covers the whole exterior.
+        let square = vec![
+            Point::new(0.0, 0.0),
+            Point::new(1.0, 0.0),
+            Point::new(1.0, 1.0),
+            Point::new(0.0, 1.0),
+        ];
+        let filled = Polygon::with_holes(square.clone(), vec![square]);
+        assert_eq!(filled.centroid(), None);
+    }
+
+    #[test]
+    fn split_by_l_shaped_chain() {
+        let square = vec![
+            Point::new(0.0, 0.0),
+            Point::new(2.0, 0.0),
+            Point::new(2.0, 2.0),
+            Point::new(0.0, 2.0),
+        ];
+        // Up through the bottom edge, then right out through the right edge.
+        let chain = vec![
+            Point::new(1.0, -1.0),
+            Point::new(1.0, 1.0),
+            Point::new(3.0, 1.0),
+        ];
+        let pieces = split_polygon_by_chain(&square, &chain).unwrap();
+        let corner = vec![
+            Point::new(2.0, 0.0),
+            Point::new(2.0, 1.0),
+            Point::new(1.0, 1.0),
+            Point::new(1.0, 0.0),
+        ];
+        let rest = vec![
+            Point::new(2.0, 2.0),
+            Point::new(0.0, 2.0),
+            Point::new(0.0, 0.0),
+            Point::new(1.0, 0.0),
+            Point::new(1.0, 1.0),
+            Point::new(2.0, 1.0),
+        ];
+        assert_eq!(pieces, vec![corner, rest]);
+        assert!(pieces.iter().all(|piece| is_ccw(piece)));
+
+        // A straight chain cuts like a line.
+        let straight = vec![Point::new(1.0, -1.0), Point::new(1.0, 3.0)];
+        let pieces = split_polygon_by_chain(&square, &straight).unwrap();
+        let mut areas: Vec<f64> = pieces.iter().map(|piece| polygon_area(piece)).collect();
+        areas.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        assert_eq!(areas, vec![2.0, 2.0]);
+
+        // Through a corner and out the opposite one.
+        let diagonal = vec![Point::new(-1.0, -1.0), Point::new(3.0, 3.0)];
+        assert_eq!(split_polygon_by_chain(&square, &diagonal).unwrap().len(), 2);
+
+        // Ending inside, crossing four times, or running along an edge.
+        let into = vec![Point::new(1.0, -1.0), Point::new(1.0, 1.0)];
+        assert_eq!(split_polygon_by_chain(&square, &into), None);
+        let zigzag = vec![
+            Point::new(0.5, -1.0),
+            Point::new(0.5, 3.0),
+            Point::new(1.5, 3.0),
+            Point::new(1.5, -1.0),
+        ];
+        assert_eq!(split_polygon_by_chain(&square, &zigzag), None);
+        let along = vec![Point::new(-1.0, 0.0), Point::new(3.0, 0.0)];
+        assert_eq!(split_polygon_by_chain(&square, &along), None);
+
+        // Starting inside, the first crossing goes out rather than in.
+        let from_inside = vec![
+            Point::new(1.0, 1.0),
+            Point::new(1.0, 3.0),
+            Point::new(1.5, 3.0),
+            Point::new(1.5, 1.0),
+        ];
+        assert_eq!(split_polygon_by_chain(&square, &from_inside), None);
+
+        // Crossing itself inside the polygon.
+        let looped = vec![
+            Point::new(0.5, -1.0),
+            Point::new(0.5, 1.5),
+            Point::new(1.5, 1.5),
+            Point::new(1.5, 0.5),
+            Point::new(0.2, 0.5),
+            Point::new(0.2, 3.0),
+        ];
+        assert_eq!(split_polygon_by_chain(&square, &looped), None);
+    }
+
+    #[test]
+    fn extra_simple_6_digits_of_accuracy() {
+        let polygon_points = vec![
+            Point { x: 0.0, y: 0.0 },
+            Point { x: 1.0, y: 0.0 },
+            Point { x: 1.0, y: 1.0 },
+            Point { x: 0.0, y: 1.0 },
+        ];
+        let lines = vec![Line {
+            p1: Point {
+                x: 0.123456789,
+                y: 0.0,
+            },
+            p2: Point {
+                x: 0.123456789,
+                y: 1.0,
+            },
+        }];
+
+        assert_eq!(
+            round_area(
+                get_largest_polygon_area(&polygon_points, &lines).unwrap(),
+                6
+            ),
+            0.876543
+        );
+    }
+}
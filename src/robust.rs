@@ -0,0 +1,138 @@
+//! Exact sign predicates using Shewchuk style expansion arithmetic.
+//! https://www.cs.cmu.edu/~quake/robust.html
+
+use crate::{Point, Scalar};
+
+/// Bound on the relative error of the plain floating point evaluation.
+/// If the result is larger than this it has the correct sign already.
+const ERROR_BOUND: f64 = (3.0 + 16.0 * f64::EPSILON / 2.0) * f64::EPSILON / 2.0;
+
+/// Product as an unevaluated sum `hi + lo` without any rounding error.
+fn two_product(a: f64, b: f64) -> (f64, f64) {
+    let hi = a * b;
+    (hi, a.mul_add(b, -hi))
+}
+
+/// Sum as an unevaluated sum `hi + lo` without any rounding error.
+fn two_sum(a: f64, b: f64) -> (f64, f64) {
+    let hi = a + b;
+    let b_virtual = hi - a;
+    let a_virtual = hi - b_virtual;
+    (hi, (a - a_virtual) + (b - b_virtual))
+}
+
+/// Adds a value to a nonoverlapping expansion, keeping it nonoverlapping
+/// and ordered from the smallest to the largest component.
+fn grow_expansion(expansion: &mut Vec<f64>, value: f64) {
+    let mut carry = value;
+    for component in expansion.iter_mut() {
+        let (hi, lo) = two_sum(carry, *component);
+        *component = lo;
+        carry = hi;
+    }
+    expansion.push(carry);
+}
+
+/// `(b - a) x (d - c)` evaluated exactly from the sum of the expanded products.
+fn exact_cross(a: [f64; 2], b: [f64; 2], c: [f64; 2], d: [f64; 2]) -> f64 {
+    let products = [
+        two_product(b[0], d[1]),
+        two_product(-b[0], c[1]),
+        two_product(-a[0], d[1]),
+        two_product(a[0], c[1]),
+        two_product(-b[1], d[0]),
+        two_product(b[1], c[0]),
+        two_product(a[1], d[0]),
+        two_product(-a[1], c[0]),
+    ];
+
+    let mut expansion = Vec::with_capacity(2 * products.len());
+    for (hi, lo) in products {
+        grow_expansion(&mut expansion, lo);
+        grow_expansion(&mut expansion, hi);
+    }
+
+    // The largest component dominates the rest, so summing from the
+    // smallest one up can't change the sign.
+    expansion.iter().sum()
+}
+
+/// `(b - a) x (d - c)` with a reliable sign, even when the two directions
+/// are parallel to within the floating point precision.
+pub(crate) fn robust_cross<T: Scalar>(a: &Point<T>, b: &Point<T>, c: &Point<T>, d: &Point<T>) -> T {
+    let a = [a.x.to_f64(), a.y.to_f64()];
+    let b = [b.x.to_f64(), b.y.to_f64()];
+    let c = [c.x.to_f64(), c.y.to_f64()];
+    let d = [d.x.to_f64(), d.y.to_f64()];
+
+    let left = (b[0] - a[0]) * (d[1] - c[1]);
+    let right = (b[1] - a[1]) * (d[0] - c[0]);
+    let cross = left - right;
+    if cross.abs() > ERROR_BOUND * (left.abs() + right.abs()) {
+        return T::from_f64(cross);
+    }
+
+    T::from_f64(exact_cross(a, b, c, d))
+}
+
+/// Orientation of three points: positive if `a`, `b`, `c` turn counter-clockwise,
+/// negative if clockwise and zero if they are exactly collinear.
+/// The magnitude is twice the area of the triangle. The sign is always exact,
+/// falling back to adaptive precision arithmetic only when the fast
+/// evaluation is too close to zero to be trusted.
+pub fn orient2d<T: Scalar>(a: &Point<T>, b: &Point<T>, c: &Point<T>) -> T {
+    robust_cross(a, b, a, c)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{orient2d, robust_cross};
+    use crate::Point;
+
+    #[test]
+    fn simple_orientations() {
+        let a = Point::new(0.0, 0.0);
+        let b = Point::new(1.0, 0.0);
+        assert_eq!(orient2d(&a, &b, &Point::new(0.0, 1.0)), 1.0);
+        assert_eq!(orient2d(&a, &b, &Point::new(0.0, -1.0)), -1.0);
+        assert_eq!(orient2d(&a, &b, &Point::new(5.0, 0.0)), 0.0);
+    }
+
+    #[test]
+    fn nearly_collinear_points() {
+        // Points within a few ulps of the diagonal through (12, 12) and (24, 24).
+        // The exact orientation is 12 * (dy - dx), which plain floating point
+        // arithmetic gets wrong for a lot of these.
+        let q = Point::new(12.0, 12.0);
+        let r = Point::new(24.0, 24.0);
+        let ulp = 0.5f64.powi(53);
+
+        let mut naive_mistakes = 0;
+        for i in 0..32 {
+            for j in 0..32 {
+                let p = Point::new(0.5 + i as f64 * ulp, 0.5 + j as f64 * ulp);
+                let expected = (p.y - 0.5).partial_cmp(&(p.x - 0.5)).unwrap();
+
+                let robust = orient2d(&p, &q, &r);
+                assert_eq!(robust.partial_cmp(&0.0).unwrap(), expected);
+
+                let naive = (q - p).cross(&(r - p));
+                if naive.partial_cmp(&0.0).unwrap() != expected {
+                    naive_mistakes += 1;
+                }
+            }
+        }
+        assert!(naive_mistakes > 0);
+    }
+
+    #[test]
+    fn parallel_directions() {
+        let a = Point::new(0.1, 0.1);
+        let b = Point::new(0.7, 0.3);
+        // Scaling by two is exact, so d - c is exactly twice b - a.
+        let c = a * 2.0;
+        let d = b * 2.0;
+        assert_eq!(robust_cross(&a, &b, &c, &d), 0.0);
+        assert!(robust_cross(&a, &b, &c, &Point::new(d.x, d.y + 1e-15)) > 0.0);
+    }
+}
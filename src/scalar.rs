@@ -0,0 +1,101 @@
+use std::fmt::{Debug, Display};
+use std::iter::Sum;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// Floating point type the geometry is computed in.
+/// Implemented for `f32` and `f64`.
+pub trait Scalar:
+    Copy
+    + Debug
+    + Display
+    + PartialOrd
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+    + AddAssign
+    + SubAssign
+    + MulAssign
+    + DivAssign
+    + Sum
+    + Send
+    + Sync
+{
+    const ZERO: Self;
+    const ONE: Self;
+    /// Default tolerance used when comparing values of this type.
+    const EPSILON: Self;
+
+    fn abs(self) -> Self;
+    fn sqrt(self) -> Self;
+    fn hypot(self, other: Self) -> Self;
+    /// Sine and cosine of the angle in radians.
+    fn sin_cos(self) -> (Self, Self);
+    /// Angle in radians of the point `(other, self)` from the positive x axis.
+    fn atan2(self, other: Self) -> Self;
+    fn round(self) -> Self;
+    /// False for NaN and the infinities.
+    fn is_finite(self) -> bool;
+    fn min(self, other: Self) -> Self;
+    fn max(self, other: Self) -> Self;
+    fn from_f64(value: f64) -> Self;
+    fn to_f64(self) -> f64;
+}
+
+macro_rules! impl_scalar {
+    ($type:ty, $epsilon:expr) => {
+        impl Scalar for $type {
+            const ZERO: Self = 0.0;
+            const ONE: Self = 1.0;
+            const EPSILON: Self = $epsilon;
+
+            fn abs(self) -> Self {
+                <$type>::abs(self)
+            }
+
+            fn sqrt(self) -> Self {
+                <$type>::sqrt(self)
+            }
+
+            fn hypot(self, other: Self) -> Self {
+                <$type>::hypot(self, other)
+            }
+
+            fn sin_cos(self) -> (Self, Self) {
+                <$type>::sin_cos(self)
+            }
+
+            fn atan2(self, other: Self) -> Self {
+                <$type>::atan2(self, other)
+            }
+
+            fn round(self) -> Self {
+                <$type>::round(self)
+            }
+
+            fn is_finite(self) -> bool {
+                <$type>::is_finite(self)
+            }
+
+            fn min(self, other: Self) -> Self {
+                <$type>::min(self, other)
+            }
+
+            fn max(self, other: Self) -> Self {
+                <$type>::max(self, other)
+            }
+
+            fn from_f64(value: f64) -> Self {
+                value as $type
+            }
+
+            fn to_f64(self) -> f64 {
+                self as f64
+            }
+        }
+    };
+}
+
+impl_scalar!(f32, 1e-5);
+impl_scalar!(f64, crate::EPSILON);
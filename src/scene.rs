@@ -0,0 +1,125 @@
+use crate::{
+    get_all_fragments_with_options, get_largest_polygon_area_with_options, Line, Point, Scalar,
+    SliceError, SliceOptions,
+};
+
+/// A polygon and the lines to cut it with, see `SceneBuilder`.
+#[derive(Debug, Clone)]
+pub struct Scene<T = f64> {
+    pub polygon: Vec<Point<T>>,
+    pub lines: Vec<Line<T>>,
+    pub options: SliceOptions<T>,
+}
+
+impl<T: Scalar> Scene<T> {
+    /// See `get_all_fragments`.
+    pub fn fragments(&self) -> Result<Vec<Vec<Point<T>>>, SliceError> {
+        get_all_fragments_with_options(&self.polygon, &self.lines, &self.options)
+    }
+
+    /// See `get_largest_polygon_area`.
+    pub fn largest_area(&self) -> Result<T, SliceError> {
+        get_largest_polygon_area_with_options(&self.polygon, &self.lines, &self.options)
+    }
+}
+
+/// Puts a `Scene` together one vertex and line at a time, e.g.
+/// `SceneBuilder::new().vertex(0.0, 0.0)...cut_line(0.5, 0.0, 0.5, 1.0).build()`.
+#[derive(Debug, Clone)]
+pub struct SceneBuilder<T = f64> {
+    scene: Scene<T>,
+}
+
+impl<T: Scalar> SceneBuilder<T> {
+    pub fn new() -> Self {
+        SceneBuilder {
+            scene: Scene {
+                polygon: Vec::new(),
+                lines: Vec::new(),
+                options: SliceOptions::default(),
+            },
+        }
+    }
+
+    /// Adds the next vertex of the polygon.
+    pub fn vertex(mut self, x: T, y: T) -> Self {
+        self.scene.polygon.push(Point::new(x, y));
+        self
+    }
+
+    /// Adds a line to cut with, after the ones already added.
+    pub fn cut_line(mut self, x1: T, y1: T, x2: T, y2: T) -> Self {
+        self.scene.lines.push(Line::from_coords(x1, y1, x2, y2));
+        self
+    }
+
+    pub fn options(mut self, options: SliceOptions<T>) -> Self {
+        self.scene.options = options;
+        self
+    }
+
+    pub fn build(self) -> Scene<T> {
+        self.scene
+    }
+}
+
+impl<T: Scalar> Default for SceneBuilder<T> {
+    fn default() -> Self {
+        SceneBuilder::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SceneBuilder;
+    use crate::{get_all_fragments, CutMode, Line, Point, SliceOptions};
+
+    fn sample() -> SceneBuilder {
+        SceneBuilder::new()
+            .vertex(0.0, 0.0)
+            .vertex(1.0, 0.0)
+            .vertex(1.0, 1.0)
+            .vertex(0.0, 1.0)
+            .cut_line(0.0, 0.0, 1.0, 1.0)
+            .cut_line(0.5, 0.0, 0.5, 1.0)
+    }
+
+    #[test]
+    fn sample_through_builder() {
+        let scene = sample().build();
+        assert_eq!(scene.largest_area(), Ok(0.375));
+        assert_eq!(scene.polygon.len(), 4);
+        assert_eq!(scene.lines.len(), 2);
+
+        let polygon_points = vec![
+            Point::new(0.0, 0.0),
+            Point::new(1.0, 0.0),
+            Point::new(1.0, 1.0),
+            Point::new(0.0, 1.0),
+        ];
+        let lines = vec![
+            Line::from_coords(0.0, 0.0, 1.0, 1.0),
+            Line::from_coords(0.5, 0.0, 0.5, 1.0),
+        ];
+        assert_eq!(
+            scene.fragments(),
+            get_all_fragments(&polygon_points, &lines)
+        );
+    }
+
+    #[test]
+    fn builder_options() {
+        // The short line only reaches across the square as an infinite line.
+        let builder = sample().cut_line(0.0, 0.5, 0.1, 0.5);
+        assert_eq!(builder.clone().build().fragments().unwrap().len(), 4);
+
+        let options = SliceOptions {
+            cut_mode: CutMode::Line,
+            ..SliceOptions::default()
+        };
+        assert_eq!(
+            builder.options(options).build().fragments().unwrap().len(),
+            6
+        );
+    }
+}
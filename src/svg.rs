@@ -0,0 +1,90 @@
+use std::fmt::Write;
+
+use crate::{bounding_box, Point, Scalar};
+
+/// Renders fragments as an SVG document of the given size, one `<polygon>` per fragment.
+/// The viewBox is fitted to the bounding box of all fragments and the y axis is
+/// flipped so the picture has the same orientation as the coordinates.
+pub fn fragments_to_svg<T: Scalar>(fragments: &[Vec<Point<T>>], width: f64, height: f64) -> String {
+    let all_points: Vec<Point<T>> = fragments.concat();
+    let (min_x, min_y, max_x, max_y) = match bounding_box(&all_points) {
+        Some((min, max)) => (
+            min.x.to_f64(),
+            min.y.to_f64(),
+            max.x.to_f64(),
+            max.y.to_f64(),
+        ),
+        // No points at all, fall back to a unit box.
+        None => (0.0, 0.0, 1.0, 1.0),
+    };
+
+    // Keep a small margin so strokes on the bounding box aren't clipped.
+    let margin = (max_x - min_x).max(max_y - min_y) * 0.02;
+    let view_width = max_x - min_x + 2.0 * margin;
+    let view_height = max_y - min_y + 2.0 * margin;
+
+    let mut svg = String::new();
+    let _ = writeln!(
+        svg,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{}" height="{}" viewBox="{} {} {} {}">"#,
+        width,
+        height,
+        min_x - margin,
+        -max_y - margin,
+        view_width,
+        view_height
+    );
+
+    for (index, fragment) in fragments.iter().enumerate() {
+        let points: Vec<String> = fragment
+            .iter()
+            .map(|p| format!("{},{}", p.x.to_f64(), -p.y.to_f64()))
+            .collect();
+
+        // Step the hue by the golden angle so neighbouring indices get distinct colors.
+        let hue = (index as f64 * 137.508) % 360.0;
+        let _ = writeln!(
+            svg,
+            r#"  <polygon points="{}" fill="hsl({:.1}, 70%, 60%)" stroke="black" stroke-width="1" vector-effect="non-scaling-stroke"/>"#,
+            points.join(" "),
+            hue
+        );
+    }
+
+    svg.push_str("</svg>\n");
+    svg
+}
+
+#[cfg(test)]
+mod tests {
+    use super::fragments_to_svg;
+    use crate::{get_all_fragments, Line, Point};
+
+    #[test]
+    fn one_polygon_per_fragment() {
+        let polygon_points = vec![
+            Point::new(0.0, 0.0),
+            Point::new(2.0, 0.0),
+            Point::new(2.0, 1.0),
+            Point::new(0.0, 1.0),
+        ];
+        let lines = vec![
+            Line::from_coords(1.0, -1.0, 1.0, 2.0),
+            Line::from_coords(-1.0, 0.5, 3.0, 0.5),
+        ];
+        let fragments = get_all_fragments(&polygon_points, &lines).unwrap();
+
+        let svg = fragments_to_svg(&fragments, 200.0, 100.0);
+        assert!(svg.starts_with("<svg "));
+        assert!(svg.trim_end().ends_with("</svg>"));
+        assert_eq!(svg.matches("<polygon").count(), 4);
+        assert!(svg.contains(r#"width="200" height="100""#));
+        assert!(svg.contains(r#"viewBox="-0.04 -1.04 2.08 1.08""#));
+    }
+
+    #[test]
+    fn empty_input() {
+        let svg = fragments_to_svg::<f64>(&[], 10.0, 10.0);
+        assert_eq!(svg.matches("<polygon").count(), 0);
+    }
+}
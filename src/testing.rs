@@ -0,0 +1,66 @@
+use crate::Point;
+
+/// Small splitmix64 generator, so the polygons only depend on the seed
+/// and not on an external random number crate.
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform value in [0, 1).
+    fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
+
+/// Random star shaped polygon with `n` vertices around the origin, fitting
+/// in the unit circle. Every vertex is visible from the origin, so the
+/// polygon is simple but usually concave. The same seed gives the same polygon.
+pub fn random_simple_polygon(n: usize, seed: u64) -> Vec<Point> {
+    random_polygon(n, seed, 0.2)
+}
+
+/// Random convex polygon with `n` vertices on the unit circle.
+/// The same seed gives the same polygon.
+pub fn random_convex_polygon(n: usize, seed: u64) -> Vec<Point> {
+    random_polygon(n, seed, 1.0)
+}
+
+/// Vertices in counter-clockwise angle order, each in its own slice of the
+/// circle so no two share an angle, at a radius between `min_radius` and 1.
+fn random_polygon(n: usize, seed: u64, min_radius: f64) -> Vec<Point> {
+    let mut rng = SplitMix64(seed);
+    (0..n)
+        .map(|i| {
+            let angle = (i as f64 + 0.1 + 0.8 * rng.next_f64()) / n as f64 * std::f64::consts::TAU;
+            let radius = min_radius + (1.0 - min_radius) * rng.next_f64();
+            Point::new(radius * angle.cos(), radius * angle.sin())
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{random_convex_polygon, random_simple_polygon};
+    use crate::{is_ccw, is_convex, is_simple};
+
+    #[test]
+    fn generated_polygons() {
+        for seed in 0..50 {
+            let polygon = random_simple_polygon(20, seed);
+            assert_eq!(polygon.len(), 20);
+            assert!(is_simple(&polygon));
+            assert!(is_ccw(&polygon));
+            assert_eq!(polygon, random_simple_polygon(20, seed));
+
+            assert!(is_convex(&random_convex_polygon(8, seed)));
+        }
+        assert_ne!(random_simple_polygon(5, 1), random_simple_polygon(5, 2));
+    }
+}
@@ -0,0 +1,101 @@
+//! Bindings for running the slicer in the browser, behind the `wasm` feature.
+//! The library is only built as a cdylib for WebAssembly, so ask for it when building:
+//!
+//! ```text
+//! cargo rustc --lib --release --target wasm32-unknown-unknown --features wasm --crate-type cdylib
+//! wasm-bindgen --target web --out-dir pkg target/wasm32-unknown-unknown/release/polygon_slicing.wasm
+//! ```
+//!
+//! Polygons and lines are passed as JSON in the serde layout of `Point` and `Line`:
+//!
+//! ```js
+//! import init, { slice_largest_area } from "./pkg/polygon_slicing.js";
+//!
+//! await init();
+//! const polygon = [{ x: 0, y: 0 }, { x: 1, y: 0 }, { x: 1, y: 1 }, { x: 0, y: 1 }];
+//! const lines = [
+//!     { p1: { x: 0, y: 0 }, p2: { x: 1, y: 1 } },
+//!     { p1: { x: 0.5, y: 0 }, p2: { x: 0.5, y: 1 } },
+//! ];
+//! slice_largest_area(JSON.stringify(polygon), JSON.stringify(lines)); // 0.375
+//! ```
+
+use std::error::Error;
+
+use wasm_bindgen::prelude::*;
+
+use crate::{get_all_fragments, get_largest_polygon_area, Line, Point};
+
+fn parse(polygon_json: &str, lines_json: &str) -> Result<(Vec<Point>, Vec<Line>), Box<dyn Error>> {
+    let polygon: Vec<Point> = serde_json::from_str(polygon_json)?;
+    let lines: Vec<Line> = serde_json::from_str(lines_json)?;
+    Ok((polygon, lines))
+}
+
+// The work is done outside the exported functions, since a `JsError` can
+// only be created when running as WebAssembly and tests run natively.
+
+fn largest_area(polygon_json: &str, lines_json: &str) -> Result<f64, Box<dyn Error>> {
+    let (polygon, lines) = parse(polygon_json, lines_json)?;
+    Ok(get_largest_polygon_area(&polygon, &lines)?)
+}
+
+fn fragments(polygon_json: &str, lines_json: &str) -> Result<String, Box<dyn Error>> {
+    let (polygon, lines) = parse(polygon_json, lines_json)?;
+    Ok(serde_json::to_string(&get_all_fragments(
+        &polygon, &lines,
+    )?)?)
+}
+
+fn js_error(error: Box<dyn Error>) -> JsError {
+    JsError::new(&error.to_string())
+}
+
+/// Largest area after cutting the polygon with every line, see `get_largest_polygon_area`.
+#[wasm_bindgen]
+pub fn slice_largest_area(polygon_json: &str, lines_json: &str) -> Result<f64, JsError> {
+    largest_area(polygon_json, lines_json).map_err(js_error)
+}
+
+/// Fragments after cutting the polygon with every line, as a JSON array of point arrays.
+#[wasm_bindgen]
+pub fn slice_fragments(polygon_json: &str, lines_json: &str) -> Result<String, JsError> {
+    fragments(polygon_json, lines_json).map_err(js_error)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{fragments, largest_area, slice_fragments, slice_largest_area};
+
+    const POLYGON: &str = r#"[{"x":0,"y":0},{"x":1,"y":0},{"x":1,"y":1},{"x":0,"y":1}]"#;
+    const LINES: &str = r#"[
+        {"p1":{"x":0,"y":0},"p2":{"x":1,"y":1}},
+        {"p1":{"x":0.5,"y":0},"p2":{"x":0.5,"y":1}}
+    ]"#;
+
+    #[test]
+    fn sample_through_json() {
+        assert_eq!(slice_largest_area(POLYGON, LINES).ok(), Some(0.375));
+
+        let fragments = slice_fragments(POLYGON, "[]").ok().unwrap();
+        assert_eq!(
+            fragments,
+            r#"[[{"x":0.0,"y":0.0},{"x":1.0,"y":0.0},{"x":1.0,"y":1.0},{"x":0.0,"y":1.0}]]"#
+        );
+    }
+
+    #[test]
+    fn invalid_input() {
+        let error = largest_area("[{\"x\": 0}]", LINES).unwrap_err();
+        assert!(error.to_string().contains("missing field `y`"));
+        assert!(fragments(POLYGON, "not json").is_err());
+
+        // Valid JSON that can't be sliced.
+        let point = r#"[{"p1":{"x":0.5,"y":0.5},"p2":{"x":0.5,"y":0.5}}]"#;
+        let error = largest_area(POLYGON, point).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            crate::SliceError::DegenerateLine.to_string()
+        );
+    }
+}
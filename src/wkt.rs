@@ -0,0 +1,135 @@
+use std::error::Error;
+use std::fmt;
+
+use crate::{points_equal, Point, Scalar};
+
+/// Error produced when a WKT string can't be read.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WktError {
+    pub message: String,
+}
+
+impl fmt::Display for WktError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl Error for WktError {}
+
+fn wkt_error(message: &str) -> WktError {
+    WktError {
+        message: message.to_string(),
+    }
+}
+
+/// Parses a WKT polygon such as `POLYGON ((0 0, 1 0, 1 1, 0 1, 0 0))`.
+/// The keyword is case insensitive and the closing vertex repeating the
+/// first one is dropped, so the result matches the open rings used elsewhere.
+/// Polygons with interior rings (holes) are rejected.
+pub fn parse_wkt_polygon(s: &str) -> Result<Vec<Point>, WktError> {
+    let s = s.trim();
+    let keyword = "POLYGON";
+    match s.get(..keyword.len()) {
+        Some(prefix) if prefix.eq_ignore_ascii_case(keyword) => {}
+        _ => return Err(wkt_error("Expected POLYGON")),
+    }
+
+    let rings = s[keyword.len()..].trim();
+    let rings = rings
+        .strip_prefix('(')
+        .and_then(|rings| rings.strip_suffix(')'))
+        .ok_or_else(|| wkt_error("Expected the rings to be wrapped in parentheses"))?
+        .trim();
+    let ring = rings
+        .strip_prefix('(')
+        .and_then(|ring| ring.strip_suffix(')'))
+        .ok_or_else(|| wkt_error("Expected a ring wrapped in parentheses"))?;
+    if ring.contains('(') || ring.contains(')') {
+        return Err(wkt_error("Polygons with holes are not supported"));
+    }
+
+    let mut points = Vec::new();
+    for coordinates in ring.split(',') {
+        let values: Vec<&str> = coordinates.split_whitespace().collect();
+        let (x, y) = match values.as_slice() {
+            [x, y] => (x, y),
+            _ => {
+                return Err(wkt_error(&format!(
+                    "Invalid point \"{}\"",
+                    coordinates.trim()
+                )))
+            }
+        };
+        let parse = |value: &str| {
+            value
+                .parse::<f64>()
+                .map_err(|_| wkt_error(&format!("Invalid number \"{}\"", value)))
+        };
+        points.push(Point::new(parse(x)?, parse(y)?));
+    }
+
+    if points.len() > 1 && points_equal(&points[0], &points[points.len() - 1]) {
+        points.pop();
+    }
+
+    Ok(points)
+}
+
+/// Formats a polygon as a WKT string, closing the ring by repeating the first vertex.
+pub fn polygon_to_wkt<T: Scalar>(points: &[Point<T>]) -> String {
+    let mut coordinates: Vec<String> = points.iter().map(|p| format!("{} {}", p.x, p.y)).collect();
+    if let Some(first) = coordinates.first() {
+        coordinates.push(first.clone());
+    }
+
+    format!("POLYGON (({}))", coordinates.join(", "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_wkt_polygon, polygon_to_wkt};
+    use crate::Point;
+
+    fn unit_square() -> Vec<Point> {
+        vec![
+            Point::new(0.0, 0.0),
+            Point::new(1.0, 0.0),
+            Point::new(1.0, 1.0),
+            Point::new(0.0, 1.0),
+        ]
+    }
+
+    #[test]
+    fn round_trip() {
+        let wkt = polygon_to_wkt(&unit_square());
+        assert_eq!(wkt, "POLYGON ((0 0, 1 0, 1 1, 0 1, 0 0))");
+        assert_eq!(parse_wkt_polygon(&wkt).unwrap(), unit_square());
+    }
+
+    #[test]
+    fn whitespace_and_open_rings() {
+        let points = parse_wkt_polygon("  polygon((0 0,1 0 , 1   1,0 1))\n").unwrap();
+        assert_eq!(points, unit_square());
+
+        let points = parse_wkt_polygon("POLYGON ((0.5 -1.5, 2 0, 1e1 3))").unwrap();
+        assert_eq!(
+            points,
+            vec![
+                Point::new(0.5, -1.5),
+                Point::new(2.0, 0.0),
+                Point::new(10.0, 3.0)
+            ]
+        );
+    }
+
+    #[test]
+    fn invalid_input() {
+        assert!(parse_wkt_polygon("LINESTRING (0 0, 1 1)").is_err());
+        assert!(parse_wkt_polygon("POLŸGON").is_err());
+        assert!(parse_wkt_polygon("POLYGON (0 0, 1 0, 1 1)").is_err());
+        assert!(parse_wkt_polygon("POLYGON ((0 0, 1 0, 1))").is_err());
+        assert!(parse_wkt_polygon("POLYGON ((0 0, 1 x, 1 1))").is_err());
+        assert!(parse_wkt_polygon("POLYGON ((0 0, 4 0, 4 4), (1 1, 2 1, 2 2))").is_err());
+    }
+}
@@ -0,0 +1,56 @@
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+/// Runs the binary in stream mode with the given stdin.
+fn run_stream(args: &[&str], input: &str) -> (String, String) {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_c"))
+        .arg("--stream")
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(input.as_bytes())
+        .unwrap();
+
+    let output = child.wait_with_output().unwrap();
+    assert!(output.status.success());
+    (
+        String::from_utf8(output.stdout).unwrap(),
+        String::from_utf8(output.stderr).unwrap(),
+    )
+}
+
+#[test]
+fn prints_largest_area_after_each_line() {
+    // Stdin carries the lines, so the input is a file, named per process
+    // so that concurrent test runs don't overwrite each other's.
+    let input_path = std::env::temp_dir().join(format!(
+        "polygon_slicing_stream_input_{}.json",
+        std::process::id()
+    ));
+    std::fs::write(
+        &input_path,
+        r#"{ "polygon": [[0, 0], [4, 0], [4, 4], [0, 4]], "lines": [] }"#,
+    )
+    .unwrap();
+
+    let (stdout, stderr) = run_stream(
+        &[input_path.to_str().unwrap()],
+        "2 -1 2 5\n\n-1 1 5 1\nnot a line\n",
+    );
+    std::fs::remove_file(&input_path).unwrap();
+    assert_eq!(stdout, "8\n6\n");
+    assert_eq!(stderr, "Skipping line 4, expected x1 y1 x2 y2\n");
+}
+
+#[test]
+fn continues_after_the_sample_lines() {
+    let (stdout, _) = run_stream(&[], "0 0.5 1 0.5\n");
+    assert_eq!(stdout, "0.25\n");
+}